@@ -0,0 +1,82 @@
+//! Page-locked memory surrounded by inaccessible guard pages
+//!
+//! This crate contains the `unsafe` code backing `cryptouri`'s `mlock`
+//! feature, so `cryptouri` itself can forbid `unsafe` code.
+
+#![deny(unsafe_op_in_unsafe_fn)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+use core::slice;
+use region::{Allocation, LockGuard, Protection};
+use zeroize::Zeroize;
+
+pub use region::{Error, Result};
+
+/// Page of memory which is locked into RAM (so it can't be swapped to disk),
+/// with an inaccessible guard page on either side of it.
+///
+/// The page is zeroized before it's unlocked and unmapped.
+pub struct GuardedPage {
+    /// Lock on the accessible page.
+    ///
+    /// Declared before `pages` so the page is unlocked before it's unmapped.
+    _lock: LockGuard,
+
+    /// Mapping of the leading guard page, accessible page and trailing guard
+    /// page (in that order)
+    pages: Allocation,
+}
+
+impl GuardedPage {
+    /// Map a new zeroed page between two guard pages and lock it into RAM
+    pub fn new() -> Result<Self> {
+        let page_size = region::page::size();
+        let pages = region::alloc(page_size * 3, Protection::NONE)?;
+        let page = pages.as_ptr::<u8>().wrapping_add(page_size);
+
+        // SAFETY: `page` is the middle page of a mapping which nothing else
+        // references, so changing its protection can't affect other memory
+        unsafe { region::protect(page, page_size, Protection::READ_WRITE)? };
+
+        let lock = region::lock(page, page_size)?;
+        Ok(Self { _lock: lock, pages })
+    }
+
+    /// Get the size of the accessible page in bytes
+    pub fn size(&self) -> usize {
+        region::page::size()
+    }
+
+    /// Borrow the contents of the accessible page
+    pub fn as_slice(&self) -> &[u8] {
+        let page = self.pages.as_ptr::<u8>().wrapping_add(self.size());
+
+        // SAFETY: the middle page is mapped readable (and zero-initialized)
+        // for as long as `self.pages` is alive, and is only reachable via
+        // `self`
+        unsafe { slice::from_raw_parts(page, self.size()) }
+    }
+
+    /// Mutably borrow the contents of the accessible page
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        let size = self.size();
+        let page = self.pages.as_mut_ptr::<u8>().wrapping_add(size);
+
+        // SAFETY: as for `as_slice`, and the page is mapped writable. The
+        // mutable borrow of `self` ensures the returned slice is unique.
+        unsafe { slice::from_raw_parts_mut(page, size) }
+    }
+}
+
+impl Drop for GuardedPage {
+    fn drop(&mut self) {
+        self.as_mut_slice().zeroize();
+    }
+}
+
+// SAFETY: the mapping is exclusively owned by the `GuardedPage` and only
+// accessed through it, like a `Box<[u8]>`
+unsafe impl Send for GuardedPage {}
+
+// SAFETY: shared access only hands out `&[u8]`
+unsafe impl Sync for GuardedPage {}
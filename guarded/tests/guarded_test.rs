@@ -0,0 +1,25 @@
+//! Guarded page tests
+
+use cryptouri_guarded::GuardedPage;
+use region::Protection;
+
+#[test]
+fn zeroed_and_writable() {
+    let mut page = GuardedPage::new().unwrap();
+    assert_eq!(page.as_slice().len(), region::page::size());
+    assert!(page.as_slice().iter().all(|&b| b == 0));
+
+    page.as_mut_slice()[..4].copy_from_slice(b"key!");
+    assert_eq!(&page.as_slice()[..4], b"key!");
+}
+
+#[test]
+fn surrounded_by_guard_pages() {
+    let page = GuardedPage::new().unwrap();
+    let start = page.as_slice().as_ptr();
+
+    let query = |address: *const u8| region::query(address).unwrap().protection();
+    assert_eq!(query(start), Protection::READ_WRITE);
+    assert_eq!(query(start.wrapping_sub(1)), Protection::NONE);
+    assert_eq!(query(start.wrapping_add(page.size())), Protection::NONE);
+}
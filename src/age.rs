@@ -0,0 +1,76 @@
+//! age recipient and identity conversions
+//!
+//! age recipients (`age1...`) and identities (`AGE-SECRET-KEY-1...`) are
+//! Bech32-encoded X25519 public and secret keys respectively.
+
+use crate::{algorithm::X25519_ALG_ID, error::Error, public_key::PublicKey, secret_key::SecretKey};
+use alloc::{borrow::ToOwned, string::String};
+use subtle_encoding::bech32::{self, Bech32};
+use zeroize::Zeroizing;
+
+/// Bech32 human-readable part of age recipients
+const RECIPIENT_HRP: &str = "age";
+
+/// Bech32 human-readable part of age identities (case-insensitive)
+const IDENTITY_HRP: &str = "age-secret-key-";
+
+impl PublicKey {
+    /// Decode an X25519 `PublicKey` from an age recipient (`age1...`)
+    pub fn from_age_recipient(recipient: &str) -> Result<Self, Error> {
+        let (hrp, key) = bech32::decode(recipient.trim()).map_err(|_| Error::Parse)?;
+
+        if hrp != RECIPIENT_HRP {
+            return Err(Error::Parse);
+        }
+
+        PublicKey::new(X25519_ALG_ID, &key)
+    }
+
+    /// Encode this X25519 `PublicKey` as an age recipient (`age1...`)
+    pub fn to_age_recipient(&self) -> Result<String, Error> {
+        if self.alg_id() != X25519_ALG_ID {
+            return Err(Error::Algorithm(self.alg_id().to_owned()));
+        }
+
+        Ok(bech32::encode(RECIPIENT_HRP, self.as_ref()))
+    }
+}
+
+impl SecretKey {
+    /// Decode an X25519 `SecretKey` from an age identity
+    /// (`AGE-SECRET-KEY-1...`).
+    ///
+    /// The contents of an identity file generated by `age-keygen` are also
+    /// accepted, in which case the first identity in the file is used.
+    pub fn from_age_identity(identity: &str) -> Result<Self, Error> {
+        let identity = identity
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+            .ok_or(Error::Parse)?;
+
+        let (hrp, key) = Bech32::upper_case()
+            .decode(identity)
+            .map_err(|_| Error::Parse)?;
+
+        let key = Zeroizing::new(key);
+
+        if hrp != IDENTITY_HRP {
+            return Err(Error::Parse);
+        }
+
+        SecretKey::new(X25519_ALG_ID, &key)
+    }
+
+    /// Encode this X25519 `SecretKey` as an age identity
+    /// (`AGE-SECRET-KEY-1...`)
+    pub fn to_age_identity(&self) -> Result<Zeroizing<String>, Error> {
+        if self.alg_id() != X25519_ALG_ID {
+            return Err(Error::Algorithm(self.alg_id().to_owned()));
+        }
+
+        // The checksum is computed over the lower case human-readable part
+        let identity = Zeroizing::new(bech32::encode(IDENTITY_HRP, self.as_bytes()));
+        Ok(Zeroizing::new(identity.to_uppercase()))
+    }
+}
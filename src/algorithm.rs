@@ -1,4 +1,13 @@
 //! Cryptographic algorithm registry
+//!
+//! Algorithm identifiers are defined as string constants (e.g.
+//! [`ED25519_ALG_ID`]), and described in detail by the [`Info`] entries in
+//! [`ALGORITHMS`], which are used to validate every parsed CryptoURI.
+
+use crate::{error::Error, kind::Kind};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
 
 //
 // Authenticated Encryption with Associated Data Algorithms
@@ -20,16 +29,448 @@ pub const CHACHA20POLY1305_ALG_ID: &str = "chacha20poly1305";
 /// NIST SHA-256 digest algorithm (FIPS 180-4)
 pub const SHA256_ALG_ID: &str = "sha256";
 
+/// NIST SHA-384 digest algorithm (FIPS 180-4)
+pub const SHA384_ALG_ID: &str = "sha384";
+
+/// NIST SHA-512 digest algorithm (FIPS 180-4)
+pub const SHA512_ALG_ID: &str = "sha512";
+
+/// NIST SHA-512/256 digest algorithm (FIPS 180-4)
+pub const SHA512T256_ALG_ID: &str = "sha512t256";
+
+/// NIST SHA3-256 digest algorithm (FIPS 202)
+pub const SHA3_256_ALG_ID: &str = "sha3-256";
+
+/// BLAKE2b digest algorithm with a 512-bit output (RFC 7693)
+pub const BLAKE2B_ALG_ID: &str = "blake2b";
+
+/// BLAKE3 digest algorithm with a 256-bit output
+pub const BLAKE3_ALG_ID: &str = "blake3";
+
 //
 // Key Derivation Algorithms
 //
 
+/// Argon2id password hashing function (RFC 9106)
+pub const ARGON2ID_ALG_ID: &str = "argon2id";
+
 /// HKDF (RFC 5869) instantiated with HMAC-SHA-256
 pub const HKDFSHA256_ALG_ID: &str = "hkdfsha256";
 
+/// HKDF (RFC 5869) instantiated with HMAC-SHA-384
+pub const HKDFSHA384_ALG_ID: &str = "hkdfsha384";
+
+/// HKDF (RFC 5869) instantiated with HMAC-SHA-512
+pub const HKDFSHA512_ALG_ID: &str = "hkdfsha512";
+
+/// NIST SP 800-108 KDF in counter mode instantiated with HMAC-SHA-256
+pub const KBKDFHMACSHA256_ALG_ID: &str = "kbkdfhmacsha256";
+
+//
+// Key Agreement Algorithms
+//
+
+/// X25519 elliptic curve Diffie-Hellman (RFC 7748)
+pub const X25519_ALG_ID: &str = "x25519";
+
+//
+// Message Authentication Code Algorithms
+//
+
+/// HMAC (RFC 2104) instantiated with SHA-256
+pub const HMACSHA256_ALG_ID: &str = "hmacsha256";
+
+/// HMAC (RFC 2104) instantiated with SHA-512
+pub const HMACSHA512_ALG_ID: &str = "hmacsha512";
+
 //
 // Signature Algorithms
 //
 
 /// Ed25519 elliptic curve digital signature algorithm (RFC 8032)
 pub const ED25519_ALG_ID: &str = "ed25519";
+
+/// Categories of cryptographic algorithms
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Category {
+    /// Authenticated Encryption with Associated Data
+    Aead,
+
+    /// Hash functions (i.e. cryptographic digests)
+    Hash,
+
+    /// Key agreement (i.e. Diffie-Hellman)
+    KeyAgreement,
+
+    /// Key derivation functions
+    Kdf,
+
+    /// Message authentication codes
+    Mac,
+
+    /// Digital signatures
+    Signature,
+}
+
+/// Length requirements for keys and other algorithm inputs/outputs
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Length {
+    /// Exactly the given number of bytes
+    Fixed(usize),
+
+    /// At least the given number of bytes
+    Min(usize),
+
+    /// At most the given number of bytes
+    Max(usize),
+}
+
+impl Length {
+    /// Check the given length satisfies this requirement
+    pub fn check(self, len: usize) -> Result<(), Error> {
+        let (valid, expected) = match self {
+            Length::Fixed(expected) => (len == expected, expected),
+            Length::Min(min) => (len >= min, min),
+            Length::Max(max) => (len <= max, max),
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::Length {
+                actual: len,
+                expected,
+            })
+        }
+    }
+
+    /// Get the smallest length which satisfies this requirement
+    pub fn minimum(self) -> usize {
+        match self {
+            Length::Fixed(len) | Length::Min(len) => len,
+            Length::Max(_) => 0,
+        }
+    }
+}
+
+/// Description of a cryptographic algorithm
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Info {
+    /// Algorithm identifier as used in CryptoURIs
+    pub id: &'static str,
+
+    /// Category of algorithm
+    pub category: Category,
+
+    /// Secret key size (if the algorithm uses secret keys)
+    pub secret_key_size: Option<Length>,
+
+    /// Public key size (if the algorithm uses public keys)
+    pub public_key_size: Option<Length>,
+
+    /// Size of the algorithm's output, i.e. a digest, MAC tag, signature,
+    /// shared secret, or derived key material
+    pub output_size: Option<Length>,
+
+    /// Kinds of CryptoURIs this algorithm may appear in
+    pub kinds: &'static [Kind],
+
+    /// ASN.1 object identifier (OID) in dotted decimal notation
+    pub oid: Option<&'static str>,
+
+    /// JSON Object Signing and Encryption (JOSE) algorithm name (i.e. a JWS
+    /// `alg` or JWE `enc` value) which identifies this algorithm on its own
+    pub jose: Option<&'static str>,
+
+    /// CBOR Object Signing and Encryption (COSE) algorithm label
+    pub cose: Option<i64>,
+}
+
+impl Info {
+    /// Can this algorithm appear in CryptoURIs of the given kind?
+    pub fn supports(&self, kind: Kind) -> bool {
+        self.kinds.contains(&kind)
+    }
+
+    /// Get the length requirement for the data of a CryptoURI of the given
+    /// kind using this algorithm (if it supports that kind)
+    pub fn length(&self, kind: Kind) -> Option<Length> {
+        if !self.supports(kind) {
+            return None;
+        }
+
+        match kind {
+            Kind::Hash | Kind::Mac | Kind::Signature => self.output_size,
+            Kind::PublicKey => self.public_key_size,
+            Kind::SecretKey => self.secret_key_size,
+            Kind::Statement => None,
+        }
+    }
+
+    /// Validate CryptoURI data of the given kind using this algorithm
+    #[cfg(feature = "alloc")]
+    pub fn validate(&self, kind: Kind, len: usize) -> Result<(), Error> {
+        self.length(kind)
+            .ok_or_else(|| Error::Algorithm(self.id.to_owned()))?
+            .check(len)
+    }
+}
+
+/// Registry of all algorithms supported by this crate
+pub const ALGORITHMS: &[Info] = &[
+    Info {
+        id: AES128GCM_ALG_ID,
+        category: Category::Aead,
+        secret_key_size: Some(Length::Fixed(16)),
+        public_key_size: None,
+        output_size: None,
+        kinds: &[Kind::SecretKey],
+        oid: Some("2.16.840.1.101.3.4.1.6"),
+        jose: Some("A128GCM"),
+        cose: Some(1),
+    },
+    Info {
+        id: AES256GCM_ALG_ID,
+        category: Category::Aead,
+        secret_key_size: Some(Length::Fixed(32)),
+        public_key_size: None,
+        output_size: None,
+        kinds: &[Kind::SecretKey],
+        oid: Some("2.16.840.1.101.3.4.1.46"),
+        jose: Some("A256GCM"),
+        cose: Some(3),
+    },
+    Info {
+        id: CHACHA20POLY1305_ALG_ID,
+        category: Category::Aead,
+        secret_key_size: Some(Length::Fixed(32)),
+        public_key_size: None,
+        output_size: None,
+        kinds: &[Kind::SecretKey],
+        oid: Some("1.2.840.113549.1.9.16.3.18"),
+        jose: None,
+        cose: Some(24),
+    },
+    Info {
+        id: SHA256_ALG_ID,
+        category: Category::Hash,
+        secret_key_size: None,
+        public_key_size: None,
+        output_size: Some(Length::Fixed(32)),
+        kinds: &[Kind::Hash],
+        oid: Some("2.16.840.1.101.3.4.2.1"),
+        jose: None,
+        cose: Some(-16),
+    },
+    Info {
+        id: SHA384_ALG_ID,
+        category: Category::Hash,
+        secret_key_size: None,
+        public_key_size: None,
+        output_size: Some(Length::Fixed(48)),
+        kinds: &[Kind::Hash],
+        oid: Some("2.16.840.1.101.3.4.2.2"),
+        jose: None,
+        cose: Some(-43),
+    },
+    Info {
+        id: SHA512_ALG_ID,
+        category: Category::Hash,
+        secret_key_size: None,
+        public_key_size: None,
+        output_size: Some(Length::Fixed(64)),
+        kinds: &[Kind::Hash],
+        oid: Some("2.16.840.1.101.3.4.2.3"),
+        jose: None,
+        cose: Some(-44),
+    },
+    Info {
+        id: SHA512T256_ALG_ID,
+        category: Category::Hash,
+        secret_key_size: None,
+        public_key_size: None,
+        output_size: Some(Length::Fixed(32)),
+        kinds: &[Kind::Hash],
+        oid: Some("2.16.840.1.101.3.4.2.6"),
+        jose: None,
+        cose: Some(-17),
+    },
+    Info {
+        id: SHA3_256_ALG_ID,
+        category: Category::Hash,
+        secret_key_size: None,
+        public_key_size: None,
+        output_size: Some(Length::Fixed(32)),
+        kinds: &[Kind::Hash],
+        oid: Some("2.16.840.1.101.3.4.2.8"),
+        jose: None,
+        cose: None,
+    },
+    Info {
+        id: BLAKE2B_ALG_ID,
+        category: Category::Hash,
+        secret_key_size: None,
+        public_key_size: None,
+        output_size: Some(Length::Fixed(64)),
+        kinds: &[Kind::Hash],
+        oid: Some("1.3.6.1.4.1.1722.12.2.1.16"),
+        jose: None,
+        cose: None,
+    },
+    Info {
+        id: BLAKE3_ALG_ID,
+        category: Category::Hash,
+        secret_key_size: None,
+        public_key_size: None,
+        output_size: Some(Length::Fixed(32)),
+        kinds: &[Kind::Hash],
+        oid: None,
+        jose: None,
+        cose: None,
+    },
+    Info {
+        id: ARGON2ID_ALG_ID,
+        category: Category::Kdf,
+        secret_key_size: Some(Length::Min(1)),
+        public_key_size: None,
+        output_size: Some(Length::Max(u32::MAX as usize)),
+        kinds: &[Kind::SecretKey],
+        oid: None,
+        jose: None,
+        cose: None,
+    },
+    Info {
+        id: HKDFSHA256_ALG_ID,
+        category: Category::Kdf,
+        secret_key_size: Some(Length::Fixed(32)),
+        public_key_size: None,
+        output_size: Some(Length::Max(255 * 32)),
+        kinds: &[Kind::SecretKey],
+        oid: Some("1.2.840.113549.1.9.16.3.28"),
+        jose: None,
+        cose: Some(-10),
+    },
+    Info {
+        id: HKDFSHA384_ALG_ID,
+        category: Category::Kdf,
+        secret_key_size: Some(Length::Fixed(48)),
+        public_key_size: None,
+        output_size: Some(Length::Max(255 * 48)),
+        kinds: &[Kind::SecretKey],
+        oid: Some("1.2.840.113549.1.9.16.3.29"),
+        jose: None,
+        cose: None,
+    },
+    Info {
+        id: HKDFSHA512_ALG_ID,
+        category: Category::Kdf,
+        secret_key_size: Some(Length::Fixed(64)),
+        public_key_size: None,
+        output_size: Some(Length::Max(255 * 64)),
+        kinds: &[Kind::SecretKey],
+        oid: Some("1.2.840.113549.1.9.16.3.30"),
+        jose: None,
+        cose: Some(-11),
+    },
+    Info {
+        id: KBKDFHMACSHA256_ALG_ID,
+        category: Category::Kdf,
+        secret_key_size: Some(Length::Fixed(32)),
+        public_key_size: None,
+        // 32-bit counter, 32-byte HMAC-SHA-256 blocks
+        output_size: Some(Length::Max((u32::MAX as usize).saturating_mul(32))),
+        kinds: &[Kind::SecretKey],
+        oid: None,
+        jose: None,
+        cose: None,
+    },
+    Info {
+        id: X25519_ALG_ID,
+        category: Category::KeyAgreement,
+        secret_key_size: Some(Length::Fixed(32)),
+        public_key_size: Some(Length::Fixed(32)),
+        output_size: Some(Length::Fixed(32)),
+        kinds: &[Kind::PublicKey, Kind::SecretKey],
+        oid: Some("1.3.101.110"),
+        jose: None,
+        cose: Some(-25),
+    },
+    Info {
+        id: HMACSHA256_ALG_ID,
+        category: Category::Mac,
+        secret_key_size: Some(Length::Min(32)),
+        public_key_size: None,
+        output_size: Some(Length::Fixed(32)),
+        kinds: &[Kind::Mac, Kind::SecretKey],
+        oid: Some("1.2.840.113549.2.9"),
+        jose: Some("HS256"),
+        cose: Some(5),
+    },
+    Info {
+        id: HMACSHA512_ALG_ID,
+        category: Category::Mac,
+        secret_key_size: Some(Length::Min(64)),
+        public_key_size: None,
+        output_size: Some(Length::Fixed(64)),
+        kinds: &[Kind::Mac, Kind::SecretKey],
+        oid: Some("1.2.840.113549.2.11"),
+        jose: Some("HS512"),
+        cose: Some(7),
+    },
+    Info {
+        id: ED25519_ALG_ID,
+        category: Category::Signature,
+        secret_key_size: Some(Length::Fixed(32)),
+        public_key_size: Some(Length::Fixed(32)),
+        output_size: Some(Length::Fixed(64)),
+        kinds: &[Kind::PublicKey, Kind::SecretKey, Kind::Signature],
+        oid: Some("1.3.101.112"),
+        jose: Some("EdDSA"),
+        cose: Some(-8),
+    },
+];
+
+/// Is the given string a well-formed algorithm identifier, i.e. non-empty
+/// and consisting only of lowercase letters, digits and `-`?
+///
+/// Other characters either can't be encoded in a Bech32 human-readable part,
+/// or clash with the delimiter, combine and fragment characters of one of
+/// the encodings.
+pub fn is_valid_id(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+/// Look up an algorithm by its CryptoURI identifier
+pub fn lookup(id: &str) -> Option<&'static Info> {
+    ALGORITHMS.iter().find(|info| info.id == id)
+}
+
+/// Look up an algorithm by its ASN.1 object identifier (dotted decimal)
+pub fn lookup_oid(oid: &str) -> Option<&'static Info> {
+    ALGORITHMS.iter().find(|info| info.oid == Some(oid))
+}
+
+/// Look up an algorithm by its JOSE algorithm name
+pub fn lookup_jose(name: &str) -> Option<&'static Info> {
+    ALGORITHMS.iter().find(|info| info.jose == Some(name))
+}
+
+/// Look up an algorithm by its COSE algorithm label
+pub fn lookup_cose(label: i64) -> Option<&'static Info> {
+    ALGORITHMS.iter().find(|info| info.cose == Some(label))
+}
+
+/// Validate that the given algorithm is supported for CryptoURIs of the
+/// given kind, and that their data is the correct length
+#[cfg(feature = "alloc")]
+pub(crate) fn validate(id: &str, kind: Kind, len: usize) -> Result<&'static Info, Error> {
+    let info = lookup(id)
+        .filter(|info| info.supports(kind))
+        .ok_or_else(|| Error::Algorithm(id.to_owned()))?;
+
+    info.validate(kind, len)?;
+    Ok(info)
+}
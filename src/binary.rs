@@ -0,0 +1,297 @@
+//! Compact binary encoding of CryptoURIs
+//!
+//! The binary encoding of a `CryptoUri` consists of:
+//!
+//! - a tag byte: the kind of CryptoURI (see [`KINDS`]), with the high bit
+//!   set if a fragment is present
+//! - the number of algorithms (more than one for KDF pipelines), followed by
+//!   each algorithm's code (see [`ALGORITHM_CODES`]), or `0` followed by the
+//!   length-prefixed algorithm identifier if (and only if) it has no code
+//! - the length-prefixed data (i.e. key, digest, tag or signature)
+//! - the length-prefixed fragment (if present)
+//!
+//! All numbers are unsigned varints, and all strings UTF-8.
+
+use crate::{
+    algorithm::{
+        self, AES128GCM_ALG_ID, AES256GCM_ALG_ID, ARGON2ID_ALG_ID, BLAKE2B_ALG_ID, BLAKE3_ALG_ID,
+        CHACHA20POLY1305_ALG_ID, ED25519_ALG_ID, HKDFSHA256_ALG_ID, HKDFSHA384_ALG_ID,
+        HKDFSHA512_ALG_ID, HMACSHA256_ALG_ID, HMACSHA512_ALG_ID, KBKDFHMACSHA256_ALG_ID,
+        SHA256_ALG_ID, SHA384_ALG_ID, SHA3_256_ALG_ID, SHA512T256_ALG_ID, SHA512_ALG_ID,
+        X25519_ALG_ID,
+    },
+    encoding::URI_ENCODING,
+    error::Error,
+    kind::Kind,
+    parts::Parts,
+    CryptoUri, CryptoUriKind,
+};
+use alloc::{borrow::ToOwned, format, vec, vec::Vec};
+use zeroize::Zeroizing;
+
+/// Tag bytes for each kind of CryptoURI
+const KINDS: &[(Kind, u8)] = &[
+    (Kind::Hash, 0x01),
+    (Kind::Mac, 0x02),
+    (Kind::PublicKey, 0x03),
+    (Kind::SecretKey, 0x04),
+    (Kind::Signature, 0x05),
+    (Kind::Statement, 0x06),
+];
+
+/// Flag set in the tag byte if a fragment is present
+const FRAGMENT_FLAG: u8 = 0x80;
+
+/// Code indicating the algorithm identifier follows
+const ALGORITHM_NAME: u64 = 0;
+
+/// Codes for algorithms in the [`algorithm`][crate::algorithm] registry.
+/// These must never be changed or reused.
+const ALGORITHM_CODES: &[(&str, u64)] = &[
+    (AES128GCM_ALG_ID, 0x01),
+    (AES256GCM_ALG_ID, 0x02),
+    (CHACHA20POLY1305_ALG_ID, 0x03),
+    (SHA256_ALG_ID, 0x10),
+    (SHA384_ALG_ID, 0x11),
+    (SHA512_ALG_ID, 0x12),
+    (SHA512T256_ALG_ID, 0x13),
+    (SHA3_256_ALG_ID, 0x14),
+    (BLAKE2B_ALG_ID, 0x15),
+    (BLAKE3_ALG_ID, 0x16),
+    (ARGON2ID_ALG_ID, 0x20),
+    (HKDFSHA256_ALG_ID, 0x21),
+    (HKDFSHA384_ALG_ID, 0x22),
+    (HKDFSHA512_ALG_ID, 0x23),
+    (KBKDFHMACSHA256_ALG_ID, 0x24),
+    (X25519_ALG_ID, 0x30),
+    (HMACSHA256_ALG_ID, 0x40),
+    (HMACSHA512_ALG_ID, 0x41),
+    (ED25519_ALG_ID, 0x50),
+];
+
+impl CryptoUri {
+    /// Decode a `CryptoUri` from its compact binary encoding
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_parts(&decode(bytes)?, URI_ENCODING, false)
+    }
+
+    /// Decode a `CryptoUri` from its compact binary encoding, returning an
+    /// opaque `Unknown` value rather than an error if its algorithm is
+    /// unknown.
+    ///
+    /// Only unknown algorithms encoded by name are passed through (which
+    /// includes every algorithm [`CryptoUri::parse_uri_lenient`] accepts).
+    /// Unknown algorithm codes (e.g. from a newer version of this crate)
+    /// still return `Error::Algorithm`, as there's no identifier to give
+    /// them in the URI.
+    pub fn from_bytes_lenient(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_parts(&decode(bytes)?, URI_ENCODING, true)
+    }
+
+    /// Encode this `CryptoUri` in its compact binary encoding
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let statement_data;
+
+        let (kind, alg_id, data) = match self.kind() {
+            CryptoUriKind::Hash(hash) => (Kind::Hash, hash.alg_id().to_owned(), hash.as_ref()),
+            CryptoUriKind::Mac(mac) => (Kind::Mac, mac.alg_id().to_owned(), mac.as_ref()),
+            CryptoUriKind::PublicKey(key) => {
+                (Kind::PublicKey, key.alg_id().to_owned(), key.as_ref())
+            }
+            CryptoUriKind::SecretKey(key) => {
+                let mut alg_id = key.alg_id().to_owned();
+
+                if let Some(pipeline) = key.pipeline() {
+                    for stage in pipeline.stages() {
+                        alg_id.push(URI_ENCODING.combine);
+                        alg_id.push_str(stage.as_str());
+                    }
+                }
+
+                (Kind::SecretKey, alg_id, key.as_bytes())
+            }
+            CryptoUriKind::Signature(sig) => {
+                (Kind::Signature, sig.alg_id().to_owned(), sig.as_ref())
+            }
+            CryptoUriKind::Statement(statement) => {
+                statement_data = statement.data();
+                (
+                    Kind::Statement,
+                    statement.alg_id(URI_ENCODING),
+                    &statement_data[..],
+                )
+            }
+            CryptoUriKind::Unknown(unknown) => (
+                unknown.kind(),
+                unknown.alg_id().to_owned(),
+                unknown.as_ref(),
+            ),
+        };
+
+        let mut tag = KINDS
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, tag)| *tag)
+            .expect("missing kind tag");
+
+        if self.fragment().is_some() {
+            tag |= FRAGMENT_FLAG;
+        }
+
+        let mut bytes = Zeroizing::new(vec![tag]);
+        let alg_ids = alg_id.split(URI_ENCODING.combine).collect::<Vec<_>>();
+        encode_varint(&mut bytes, alg_ids.len() as u64);
+
+        for alg_id in alg_ids {
+            match ALGORITHM_CODES.iter().find(|(id, _)| *id == alg_id) {
+                Some((_, code)) => encode_varint(&mut bytes, *code),
+                None => {
+                    encode_varint(&mut bytes, ALGORITHM_NAME);
+                    write_bytes(&mut bytes, alg_id.as_bytes());
+                }
+            }
+        }
+
+        write_bytes(&mut bytes, data);
+
+        if let Some(fragment) = self.fragment() {
+            write_bytes(&mut bytes, fragment.as_bytes());
+        }
+
+        bytes
+    }
+}
+
+/// Decode the compact binary encoding of a CryptoURI into the parts of its
+/// equivalent URI
+fn decode(bytes: &[u8]) -> Result<Parts, Error> {
+    let (&tag, mut bytes) = bytes.split_first().ok_or(Error::Parse)?;
+
+    let kind = KINDS
+        .iter()
+        .find(|(_, t)| *t == tag & !FRAGMENT_FLAG)
+        .map(|(kind, _)| *kind)
+        .ok_or(Error::Parse)?;
+
+    let mut prefix = URI_ENCODING.scheme(kind).to_owned();
+    let (count, rest) = decode_varint(bytes)?;
+    bytes = rest;
+
+    if count == 0 {
+        return Err(Error::Parse);
+    }
+
+    for i in 0..count {
+        if i > 0 {
+            prefix.push(URI_ENCODING.combine);
+        }
+
+        let (code, rest) = decode_varint(bytes)?;
+        bytes = rest;
+
+        if code == ALGORITHM_NAME {
+            let (alg_id, rest) = read_bytes(bytes)?;
+            bytes = rest;
+
+            let alg_id = core::str::from_utf8(alg_id).map_err(|_| Error::Parse)?;
+
+            // Algorithms with a code must be encoded using it, so each
+            // `CryptoUri` has exactly one binary encoding
+            if !algorithm::is_valid_id(alg_id)
+                || ALGORITHM_CODES.iter().any(|(id, _)| *id == alg_id)
+            {
+                return Err(Error::Parse);
+            }
+
+            prefix.push_str(alg_id);
+        } else {
+            let (alg_id, _) = ALGORITHM_CODES
+                .iter()
+                .find(|(_, c)| *c == code)
+                .ok_or_else(|| Error::Algorithm(format!("0x{:x}", code)))?;
+
+            prefix.push_str(alg_id);
+        }
+    }
+
+    let (data, rest) = read_bytes(bytes)?;
+    bytes = rest;
+
+    let fragment = if tag & FRAGMENT_FLAG != 0 {
+        let (fragment, rest) = read_bytes(bytes)?;
+        bytes = rest;
+
+        Some(
+            core::str::from_utf8(fragment)
+                .map_err(|_| Error::Parse)?
+                .to_owned(),
+        )
+    } else {
+        None
+    };
+
+    if !bytes.is_empty() {
+        return Err(Error::Parse);
+    }
+
+    Ok(Parts {
+        prefix,
+        data: data.to_vec(),
+        fragment,
+    })
+}
+
+/// Write length-prefixed bytes
+fn write_bytes(buf: &mut Vec<u8>, data: &[u8]) {
+    encode_varint(buf, data.len() as u64);
+    buf.extend_from_slice(data);
+}
+
+/// Read length-prefixed bytes, returning them and the remaining bytes
+fn read_bytes(bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    let (len, bytes) = decode_varint(bytes)?;
+    let len = usize::try_from(len).map_err(|_| Error::Parse)?;
+
+    if bytes.len() < len {
+        return Err(Error::Parse);
+    }
+
+    Ok(bytes.split_at(len))
+}
+
+/// Decode an unsigned varint, returning it and the remaining bytes. Only the
+/// minimal encoding of each value is accepted.
+pub(crate) fn decode_varint(bytes: &[u8]) -> Result<(u64, &[u8]), Error> {
+    let mut value = 0u64;
+
+    // Multiformats varints are limited to 9 bytes
+    for (i, &byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+
+        if byte & 0x80 == 0 {
+            // A trailing zero byte could have been omitted
+            if i > 0 && byte == 0 {
+                return Err(Error::Parse);
+            }
+
+            return Ok((value, &bytes[(i + 1)..]));
+        }
+    }
+
+    Err(Error::Parse)
+}
+
+/// Encode an unsigned varint onto the end of the given buffer
+pub(crate) fn encode_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            buf.push(byte);
+            return;
+        }
+
+        buf.push(byte | 0x80);
+    }
+}
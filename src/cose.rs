@@ -0,0 +1,268 @@
+//! COSE_Key (RFC 9052) CBOR conversions for keys and signatures
+//!
+//! Ed25519 and X25519 keys use the `OKP` key type and symmetric keys use the
+//! `Symmetric` key type (RFC 9053), with the `alg` label taken from the
+//! [`algorithm`] registry. Maps are encoded with their labels in
+//! deterministic (RFC 8949 Section 4.2.1) order.
+//!
+//! `OKP` private keys are encoded without their (optional) public key, but
+//! if one is present when decoding, it must match the secret key.
+
+use crate::{
+    algorithm::{self, Info, ED25519_ALG_ID, X25519_ALG_ID},
+    error::Error,
+    kind::Kind,
+    public_key::PublicKey,
+    secret_key::SecretKey,
+    signature::Signature,
+};
+use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
+use ciborium::value::{Integer, Value};
+use zeroize::{Zeroize, Zeroizing};
+
+/// COSE_Key map
+type CoseKey = Vec<(Value, Value)>;
+
+/// Key type label
+const KTY: i64 = 1;
+
+/// Algorithm label
+const ALG: i64 = 3;
+
+/// `OKP` curve label
+const OKP_CRV: i64 = -1;
+
+/// `OKP` public key label
+const OKP_X: i64 = -2;
+
+/// `OKP` private key label
+const OKP_D: i64 = -4;
+
+/// `Symmetric` key value label
+const SYMMETRIC_K: i64 = -1;
+
+/// `OKP` key type
+const KTY_OKP: i64 = 1;
+
+/// `Symmetric` key type
+const KTY_SYMMETRIC: i64 = 4;
+
+/// Curve identifiers for `OKP` keys
+const OKP_CURVES: &[(&str, i64)] = &[(ED25519_ALG_ID, 6), (X25519_ALG_ID, 4)];
+
+impl PublicKey {
+    /// Decode a `PublicKey` from a CBOR-encoded COSE_Key
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, Error> {
+        let mut key = parse(bytes)?;
+        let result = public_key_from_map(&key);
+        zeroize_map(&mut key);
+        result
+    }
+
+    /// Encode this `PublicKey` as a CBOR-encoded COSE_Key
+    pub fn to_cose_key(&self) -> Result<Vec<u8>, Error> {
+        let info = algorithm::lookup(self.alg_id())
+            .ok_or_else(|| Error::Algorithm(self.alg_id().to_owned()))?;
+
+        let crv = okp_curve(info)?;
+        let mut key = CoseKey::new();
+        insert(&mut key, KTY, integer(KTY_OKP));
+        insert_alg(&mut key, info);
+        insert(&mut key, OKP_CRV, integer(crv));
+        insert(&mut key, OKP_X, Value::Bytes(self.as_ref().to_vec()));
+        Ok(serialize(key))
+    }
+}
+
+impl SecretKey {
+    /// Decode a `SecretKey` from a CBOR-encoded COSE_Key
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, Error> {
+        let mut key = parse(bytes)?;
+        let result = secret_key_from_map(&key);
+        zeroize_map(&mut key);
+        result
+    }
+
+    /// Encode this `SecretKey` as a CBOR-encoded COSE_Key
+    pub fn to_cose_key(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let info = algorithm::lookup(self.alg_id())
+            .filter(|_| self.pipeline().is_none())
+            .ok_or_else(|| Error::Algorithm(self.alg_id().to_owned()))?;
+
+        let mut key = CoseKey::new();
+
+        if info.public_key_size.is_some() {
+            let crv = okp_curve(info)?;
+            insert(&mut key, KTY, integer(KTY_OKP));
+            insert_alg(&mut key, info);
+            insert(&mut key, OKP_CRV, integer(crv));
+            insert(&mut key, OKP_D, Value::Bytes(self.as_bytes().to_vec()));
+        } else {
+            // Symmetric keys are identified solely by their algorithm
+            info.cose
+                .ok_or_else(|| Error::Algorithm(info.id.to_owned()))?;
+
+            insert(&mut key, KTY, integer(KTY_SYMMETRIC));
+            insert_alg(&mut key, info);
+            insert(
+                &mut key,
+                SYMMETRIC_K,
+                Value::Bytes(self.as_bytes().to_vec()),
+            );
+        }
+
+        Ok(Zeroizing::new(serialize(key)))
+    }
+}
+
+impl Signature {
+    /// Create a `Signature` from the signature bytes of a COSE_Sign1 or
+    /// COSE_Signature structure using the given COSE algorithm
+    pub fn from_cose(alg: i64, bytes: &[u8]) -> Result<Self, Error> {
+        let info = algorithm::lookup_cose(alg)
+            .filter(|info| info.supports(Kind::Signature))
+            .ok_or_else(|| Error::Algorithm(alg.to_string()))?;
+
+        Signature::new(info.id, bytes)
+    }
+
+    /// Get the COSE algorithm for this `Signature`. Its COSE signature bytes
+    /// are the same as its CryptoURI data (see `AsRef<[u8]>`).
+    pub fn cose_alg(&self) -> Result<i64, Error> {
+        algorithm::lookup(self.alg_id())
+            .and_then(|info| info.cose)
+            .ok_or_else(|| Error::Algorithm(self.alg_id().to_owned()))
+    }
+}
+
+/// Parse a CBOR-encoded COSE_Key
+fn parse(bytes: &[u8]) -> Result<CoseKey, Error> {
+    ciborium::de::from_reader::<Value, _>(bytes)
+        .map_err(|_| Error::Parse)?
+        .into_map()
+        .map_err(|_| Error::Parse)
+}
+
+/// Serialize a COSE_Key as CBOR, zeroizing the map afterward
+fn serialize(key: CoseKey) -> Vec<u8> {
+    let mut value = Value::Map(key);
+    let mut bytes = Vec::new();
+    ciborium::ser::into_writer(&value, &mut bytes).expect("COSE_Key serialization failed");
+
+    if let Some(key) = value.as_map_mut() {
+        zeroize_map(key);
+    }
+
+    bytes
+}
+
+/// Create a CBOR integer value
+fn integer(value: i64) -> Value {
+    Value::Integer(value.into())
+}
+
+/// Insert a label and value into a COSE_Key
+fn insert(key: &mut CoseKey, label: i64, value: Value) {
+    key.push((integer(label), value));
+}
+
+/// Insert the `alg` label for the given algorithm (if it has one)
+fn insert_alg(key: &mut CoseKey, info: &Info) {
+    if let Some(alg) = info.cose {
+        insert(key, ALG, integer(alg));
+    }
+}
+
+/// Get the value for the given label in a COSE_Key
+fn get(key: &CoseKey, label: i64) -> Option<&Value> {
+    let label = Integer::from(label);
+
+    key.iter()
+        .find(|(l, _)| l.as_integer() == Some(label))
+        .map(|(_, value)| value)
+}
+
+/// Get the integer value for the given label in a COSE_Key
+fn get_integer(key: &CoseKey, label: i64) -> Result<i64, Error> {
+    get(key, label)
+        .and_then(Value::as_integer)
+        .and_then(|value| i64::try_from(value).ok())
+        .ok_or(Error::Parse)
+}
+
+/// Get the byte string value for the given label in a COSE_Key
+fn get_bytes(key: &CoseKey, label: i64) -> Result<&[u8], Error> {
+    get(key, label)
+        .and_then(Value::as_bytes)
+        .map(Vec::as_slice)
+        .ok_or(Error::Parse)
+}
+
+/// Find the algorithm for an `OKP` key from its curve identifier
+fn okp_algorithm(key: &CoseKey) -> Result<&'static str, Error> {
+    let crv = get_integer(key, OKP_CRV)?;
+
+    OKP_CURVES
+        .iter()
+        .find(|(_, id)| *id == crv)
+        .map(|(alg_id, _)| *alg_id)
+        .ok_or_else(|| Error::Algorithm(crv.to_string()))
+}
+
+/// Get the curve identifier for the given algorithm if it's used with `OKP`
+fn okp_curve(info: &Info) -> Result<i64, Error> {
+    OKP_CURVES
+        .iter()
+        .find(|(alg_id, _)| *alg_id == info.id)
+        .map(|(_, crv)| *crv)
+        .ok_or_else(|| Error::Algorithm(info.id.to_owned()))
+}
+
+/// Decode a `PublicKey` from a COSE_Key
+fn public_key_from_map(key: &CoseKey) -> Result<PublicKey, Error> {
+    if get_integer(key, KTY)? != KTY_OKP || get(key, OKP_D).is_some() {
+        return Err(Error::Parse);
+    }
+
+    PublicKey::new(okp_algorithm(key)?, get_bytes(key, OKP_X)?)
+}
+
+/// Decode a `SecretKey` from a COSE_Key
+fn secret_key_from_map(key: &CoseKey) -> Result<SecretKey, Error> {
+    match get_integer(key, KTY)? {
+        KTY_OKP => {
+            let secret_key = SecretKey::new(okp_algorithm(key)?, get_bytes(key, OKP_D)?)?;
+
+            // The public key (`x`) is optional, but must match if present
+            if get(key, OKP_X).is_some() {
+                let public_key = secret_key
+                    .public_key()
+                    .ok_or_else(|| Error::Algorithm(secret_key.alg_id().to_owned()))?;
+
+                if get_bytes(key, OKP_X)? != public_key.as_ref() {
+                    return Err(Error::Verification);
+                }
+            }
+
+            Ok(secret_key)
+        }
+        KTY_SYMMETRIC => {
+            let alg = get_integer(key, ALG)?;
+            let info = algorithm::lookup_cose(alg)
+                .filter(|info| info.supports(Kind::SecretKey) && info.public_key_size.is_none())
+                .ok_or_else(|| Error::Algorithm(alg.to_string()))?;
+
+            SecretKey::new(info.id, get_bytes(key, SYMMETRIC_K)?)
+        }
+        _ => Err(Error::Parse),
+    }
+}
+
+/// Zeroize the byte strings in a COSE_Key, which may contain secret keys
+fn zeroize_map(key: &mut CoseKey) {
+    for (_, value) in key.iter_mut() {
+        if let Some(bytes) = value.as_bytes_mut() {
+            bytes.zeroize();
+        }
+    }
+}
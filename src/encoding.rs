@@ -1,11 +1,29 @@
 //! CryptoURI encoding support
 
+use crate::{error::Error, heapless::Encoder};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{heapless, kind::Kind},
+    alloc::{string::String, vec, vec::Vec},
+    core::mem,
+    subtle_encoding::{
+        base64,
+        bech32::{self, Bech32},
+        hex,
+    },
+    zeroize::Zeroizing,
+};
+
 /// Characters to use when encoding CryptoUris
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) struct Encoding {
     /// Scheme prefix for hashes
     pub hash_scheme: &'static str,
 
+    /// Scheme prefix for message authentication codes
+    pub mac_scheme: &'static str,
+
     /// Scheme prefix for public keys
     pub public_key_scheme: &'static str,
 
@@ -15,6 +33,9 @@ pub(crate) struct Encoding {
     /// Scheme prefix for signatures
     pub signature_scheme: &'static str,
 
+    /// Scheme prefix for signed statements
+    pub statement_scheme: &'static str,
+
     /// Bech32 delimiter which separates "Human Readable Part" from binary part
     pub delimiter: char,
 
@@ -25,14 +46,31 @@ pub(crate) struct Encoding {
     pub fragment_delimiter: Option<char>,
 }
 
+impl Encoding {
+    /// Get the scheme prefix for the given kind of CryptoURI
+    #[cfg(feature = "alloc")]
+    pub fn scheme(&self, kind: Kind) -> &'static str {
+        match kind {
+            Kind::Hash => self.hash_scheme,
+            Kind::Mac => self.mac_scheme,
+            Kind::PublicKey => self.public_key_scheme,
+            Kind::SecretKey => self.secret_key_scheme,
+            Kind::Signature => self.signature_scheme,
+            Kind::Statement => self.statement_scheme,
+        }
+    }
+}
+
 // TODO: compute schemes using delimiter rather than hardcoding each one
 
 /// Normal URI encoding
 pub(crate) const URI_ENCODING: &Encoding = &Encoding {
     hash_scheme: "crypto:hash:",
+    mac_scheme: "crypto:mac:",
     public_key_scheme: "crypto:pub:key:",
     secret_key_scheme: "crypto:sec:key:",
     signature_scheme: "crypto:sig:",
+    statement_scheme: "crypto:stmt:",
     delimiter: ':',
     combine: '+',
     fragment_delimiter: Some('#'),
@@ -41,9 +79,11 @@ pub(crate) const URI_ENCODING: &Encoding = &Encoding {
 /// URI-embeddable (a.k.a. "dasherized") encoding
 pub(crate) const DASHERIZED_ENCODING: &Encoding = &Encoding {
     hash_scheme: "crypto-hash-",
+    mac_scheme: "crypto-mac-",
     public_key_scheme: "crypto-pub-key-",
     secret_key_scheme: "crypto-sec-key-",
     signature_scheme: "crypto-sig-",
+    statement_scheme: "crypto-stmt-",
     delimiter: '-',
     combine: '_',
     fragment_delimiter: None,
@@ -52,41 +92,277 @@ pub(crate) const DASHERIZED_ENCODING: &Encoding = &Encoding {
 /// Objects that can be encoded as CryptoUri
 pub trait Encodable {
     /// Encode this object in URI generic syntax
+    #[cfg(feature = "alloc")]
     fn to_uri_string(&self) -> String;
 
     /// Encode this object in URI-embeddable "dasherized" format
+    #[cfg(feature = "alloc")]
     fn to_dasherized_string(&self) -> String;
+
+    /// Encode this object in URI generic syntax into the given buffer,
+    /// returning the encoded string (or `Error::Length` if the buffer is too
+    /// small).
+    ///
+    /// This crate's types implement this without allocating. The default
+    /// implementation copies the output of [`Encodable::to_uri_string`].
+    #[cfg(feature = "alloc")]
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+        let uri = Zeroizing::new(self.to_uri_string());
+
+        if uri.len() > buf.len() {
+            return Err(Error::Length {
+                actual: buf.len(),
+                expected: uri.len(),
+            });
+        }
+
+        let out = &mut buf[..uri.len()];
+        out.copy_from_slice(uri.as_bytes());
+        core::str::from_utf8(out).map_err(|_| Error::Parse)
+    }
+
+    /// Encode this object in URI generic syntax into the given buffer,
+    /// returning the encoded string (or `Error::Length` if the buffer is too
+    /// small)
+    #[cfg(not(feature = "alloc"))]
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error>;
+}
+
+/// Encode a CryptoURI with the given scheme, algorithm and data into the
+/// given buffer without allocating
+pub(crate) fn encode_into<'a>(
+    buf: &'a mut [u8],
+    scheme: &str,
+    alg_id: &str,
+    data: &[u8],
+) -> Result<&'a str, Error> {
+    let mut encoder = Encoder::new(buf);
+    encoder.push(scheme);
+    encoder.push(alg_id);
+    encoder.finish(URI_ENCODING.delimiter, data)
+}
+
+/// Encode a CryptoURI of the given kind into a string which is wiped on
+/// drop, without leaving copies of the data or its encoding anywhere else
+/// (e.g. in intermediate buffers)
+#[cfg(feature = "alloc")]
+pub(crate) fn encode_zeroizing(
+    encoding: &Encoding,
+    kind: Kind,
+    alg_id: &str,
+    data: &[u8],
+) -> Zeroizing<String> {
+    let scheme = encoding.scheme(kind);
+    let mut buf = Zeroizing::new(vec![
+        0u8;
+        heapless::encoded_len(
+            scheme.len() + alg_id.len(),
+            data.len()
+        )
+    ]);
+
+    let mut encoder = Encoder::new(&mut buf);
+    encoder.push(scheme);
+    encoder.push(alg_id);
+
+    if encoder.finish(encoding.delimiter, data).is_err() {
+        // Algorithm identifiers are validated when objects are created, so
+        // the human-readable part should always be valid. Never panic if it
+        // isn't though: encode it as-is (without the zeroizing guarantees)
+        return Zeroizing::new(
+            Bech32::new(bech32::DEFAULT_CHARSET, encoding.delimiter)
+                .encode(String::from(scheme) + alg_id, data),
+        );
+    }
+
+    Zeroizing::new(String::from_utf8(mem::take(&mut *buf)).expect("Bech32 is ASCII"))
+}
+
+/// Encode the given bytes as lowercase hex
+#[cfg(feature = "alloc")]
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    String::from_utf8(hex::encode(bytes)).expect("hex is ASCII")
+}
+
+/// Decode the given hex string (in constant time, case insensitively)
+#[cfg(feature = "alloc")]
+pub(crate) fn decode_hex(encoded: &str) -> Result<Vec<u8>, Error> {
+    let encoded = Zeroizing::new(encoded.trim().to_ascii_lowercase());
+    hex::decode(encoded.as_bytes()).map_err(|_| Error::Parse)
+}
+
+/// Encode the given bytes as (padded) RFC 4648 base64
+#[cfg(feature = "alloc")]
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    String::from_utf8(base64::encode(bytes)).expect("base64 is ASCII")
+}
+
+/// Decode the given (padded) RFC 4648 base64 string (in constant time)
+#[cfg(feature = "alloc")]
+pub(crate) fn decode_base64(encoded: &str) -> Result<Vec<u8>, Error> {
+    base64::decode(encoded.trim()).map_err(|_| Error::Parse)
+}
+
+/// Implement hex and base64 conversions for a type with a `new(alg, bytes)`
+/// constructor which validates the length for the given algorithm
+#[cfg(feature = "alloc")]
+macro_rules! impl_hex_base64 {
+    ($name:ident) => {
+        impl $name {
+            /// Decode from a hex string using the given algorithm
+            pub fn from_hex(alg: &str, encoded: &str) -> Result<Self, Error> {
+                Self::new(alg, &$crate::encoding::decode_hex(encoded)?)
+            }
+
+            /// Encode as a lowercase hex string
+            pub fn to_hex(&self) -> alloc::string::String {
+                $crate::encoding::encode_hex(self.as_ref())
+            }
+
+            /// Decode from a base64 string using the given algorithm
+            pub fn from_base64(alg: &str, encoded: &str) -> Result<Self, Error> {
+                Self::new(alg, &$crate::encoding::decode_base64(encoded)?)
+            }
+
+            /// Encode as a base64 string
+            pub fn to_base64(&self) -> alloc::string::String {
+                $crate::encoding::encode_base64(self.as_ref())
+            }
+        }
+    };
+}
+
+/// Implement constant-time equality for a type whose bytes are accessible
+/// via `AsRef`
+macro_rules! impl_constant_time_eq {
+    ($name:ident) => {
+        impl subtle::ConstantTimeEq for $name {
+            fn ct_eq(&self, other: &Self) -> subtle::Choice {
+                subtle::ConstantTimeEq::ct_eq(&self.as_ref()[..], &other.as_ref()[..])
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                subtle::ConstantTimeEq::ct_eq(self, other).into()
+            }
+        }
+
+        impl Eq for $name {}
+    };
+}
+
+/// Implement `TryFrom<CryptoUri>` for a concrete type, i.e. the given
+/// variant of the given kind of `CryptoUri`
+macro_rules! impl_try_from_crypto_uri {
+    ($name:ident, $kind:ident, $variant:path, $alg:expr) => {
+        #[cfg(feature = "alloc")]
+        impl TryFrom<$crate::CryptoUri> for $name {
+            type Error = $crate::error::Error;
+
+            fn try_from(uri: $crate::CryptoUri) -> Result<Self, $crate::error::Error> {
+                match uri.into_kind() {
+                    $crate::CryptoUriKind::$kind($variant(inner)) => Ok(inner),
+                    other => Err(other.type_error($crate::kind::Kind::$kind, $alg)),
+                }
+            }
+        }
+    };
+}
+
+/// Implement `TryFrom<CryptoUri>` for the type of object stored in the given
+/// variant of `CryptoUriKind`
+#[cfg(feature = "alloc")]
+macro_rules! impl_try_from_crypto_uri_kind {
+    ($name:ident) => {
+        impl TryFrom<$crate::CryptoUri> for $name {
+            type Error = $crate::error::Error;
+
+            fn try_from(uri: $crate::CryptoUri) -> Result<Self, $crate::error::Error> {
+                match uri.into_kind() {
+                    $crate::CryptoUriKind::$name(inner) => Ok(inner),
+                    other => Err($crate::error::Error::Kind {
+                        expected: $crate::kind::Kind::$name,
+                        actual: other.kind(),
+                    }),
+                }
+            }
+        }
+    };
 }
 
 macro_rules! impl_encodable {
     ($scheme:ident, $name:ident, $alg:expr) => {
         impl crate::encoding::Encodable for $name {
+            #[cfg(feature = "alloc")]
             #[inline]
-            fn to_uri_string(&self) -> String {
+            fn to_uri_string(&self) -> alloc::string::String {
                 use subtle_encoding::bech32::{self, Bech32};
                 Bech32::new(
                     bech32::DEFAULT_CHARSET,
                     $crate::encoding::URI_ENCODING.delimiter,
                 )
                 .encode(
-                    $crate::encoding::URI_ENCODING.$scheme.to_owned() + $alg,
+                    alloc::string::String::from($crate::encoding::URI_ENCODING.$scheme) + $alg,
                     &self.0[..],
                 )
             }
 
+            #[cfg(feature = "alloc")]
             #[inline]
-            fn to_dasherized_string(&self) -> String {
+            fn to_dasherized_string(&self) -> alloc::string::String {
                 use subtle_encoding::bech32::{self, Bech32};
                 Bech32::new(
                     bech32::DEFAULT_CHARSET,
                     $crate::encoding::DASHERIZED_ENCODING.delimiter,
                 )
                 .encode(
-                    $crate::encoding::DASHERIZED_ENCODING.$scheme.to_owned() + $alg,
+                    alloc::string::String::from($crate::encoding::DASHERIZED_ENCODING.$scheme)
+                        + $alg,
+                    &self.0[..],
+                )
+            }
+
+            #[inline]
+            fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, $crate::error::Error> {
+                $crate::encoding::encode_into(
+                    buf,
+                    $crate::encoding::URI_ENCODING.$scheme,
+                    $alg,
                     &self.0[..],
                 )
             }
         }
+
+        impl $name {
+            /// Parse from a CryptoURI without allocating. Any fragment is
+            /// ignored.
+            pub fn parse_uri(uri: &str) -> Result<Self, $crate::error::Error> {
+                Self::decode_heapless(uri, $crate::encoding::URI_ENCODING)
+            }
+
+            /// Parse from a CryptoURI in URI-embeddable (a.k.a.
+            /// "dasherized") encoding without allocating
+            pub fn parse_dasherized(token: &str) -> Result<Self, $crate::error::Error> {
+                Self::decode_heapless(token, $crate::encoding::DASHERIZED_ENCODING)
+            }
+
+            /// Decode from the given encoding without allocating
+            fn decode_heapless(
+                uri: &str,
+                encoding: &$crate::encoding::Encoding,
+            ) -> Result<Self, $crate::error::Error> {
+                let mut bytes = [0u8; core::mem::size_of::<$name>()];
+                $crate::heapless::decode_prefixed(
+                    uri,
+                    encoding,
+                    encoding.$scheme,
+                    $alg,
+                    &mut bytes,
+                )?;
+                Ok($name(bytes))
+            }
+        }
     };
 }
 
@@ -96,41 +372,49 @@ macro_rules! impl_encodable_hash {
     };
 }
 
+macro_rules! impl_encodable_mac {
+    ($name:ident, $alg:expr) => {
+        impl_encodable!(mac_scheme, $name, $alg);
+    };
+}
+
 macro_rules! impl_encodable_public_key {
     ($name:ident, $alg:expr) => {
         impl_encodable!(public_key_scheme, $name, $alg);
     };
 }
 
+#[cfg(feature = "alloc")]
 macro_rules! impl_encodable_secret_key {
     ($name:ident, $alg:expr) => {
         impl crate::encoding::Encodable for $name {
             #[inline]
-            fn to_uri_string(&self) -> String {
-                use subtle_encoding::bech32::{self, Bech32};
-                Bech32::new(
-                    bech32::DEFAULT_CHARSET,
-                    $crate::encoding::URI_ENCODING.delimiter,
-                )
-                .encode(
-                    $crate::encoding::URI_ENCODING.secret_key_scheme.to_owned() + $alg,
+            fn to_uri_string(&self) -> alloc::string::String {
+                core::mem::take(&mut *$crate::encoding::encode_zeroizing(
+                    $crate::encoding::URI_ENCODING,
+                    $crate::kind::Kind::SecretKey,
+                    $alg,
                     &self.as_ref()[..],
-                )
+                ))
             }
 
             #[inline]
-            fn to_dasherized_string(&self) -> String {
-                use subtle_encoding::bech32::{self, Bech32};
-                Bech32::new(
-                    bech32::DEFAULT_CHARSET,
-                    $crate::encoding::DASHERIZED_ENCODING.delimiter,
-                )
-                .encode(
-                    $crate::encoding::DASHERIZED_ENCODING
-                        .secret_key_scheme
-                        .to_owned()
-                        + $alg,
+            fn to_dasherized_string(&self) -> alloc::string::String {
+                core::mem::take(&mut *$crate::encoding::encode_zeroizing(
+                    $crate::encoding::DASHERIZED_ENCODING,
+                    $crate::kind::Kind::SecretKey,
+                    $alg,
                     &self.as_ref()[..],
+                ))
+            }
+
+            #[inline]
+            fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, $crate::error::Error> {
+                $crate::encoding::encode_into(
+                    buf,
+                    $crate::encoding::URI_ENCODING.secret_key_scheme,
+                    $alg,
+                    self.as_ref(),
                 )
             }
         }
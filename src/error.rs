@@ -1,16 +1,32 @@
 //! Error types
 
-use std::fmt::{self, Display};
+use crate::kind::Kind;
+use core::fmt::{self, Display};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 
 /// Kinds of errors
+///
+/// The variants which carry strings require the `alloc` cargo feature.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Error {
     /// Unknown or unsupported algorithm
+    #[cfg(feature = "alloc")]
     Algorithm(String),
 
     /// Checksum error
     Checksum,
 
+    /// object is not of the expected kind
+    Kind {
+        /// Expected kind
+        expected: Kind,
+
+        /// Actual kind
+        actual: Kind,
+    },
+
     /// Length error
     Length {
         /// Actual length
@@ -24,21 +40,59 @@ pub enum Error {
     Parse,
 
     /// unknown URI scheme
+    #[cfg(feature = "alloc")]
     Scheme(String),
+
+    /// object is not of the expected type (i.e. kind and algorithm)
+    #[cfg(feature = "alloc")]
+    Type {
+        /// Actual kind
+        actual_kind: Kind,
+
+        /// Actual algorithm
+        actual_alg: String,
+
+        /// Expected kind
+        expected_kind: Kind,
+
+        /// Expected algorithm
+        expected_alg: &'static str,
+    },
+
+    /// verification failed
+    Verification,
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            #[cfg(feature = "alloc")]
             Error::Algorithm(alg) => write!(f, "algorithm invalid: '{}'", alg),
             Error::Checksum => write!(f, "checksum invalid"),
+            Error::Kind { expected, actual } => {
+                write!(f, "kind invalid: {} (expected {})", actual, expected)
+            }
             Error::Length { expected, actual } => {
                 write!(f, "length invalid: {} (expected {})", actual, expected)
             }
             Error::Parse => write!(f, "parse error"),
+            #[cfg(feature = "alloc")]
             Error::Scheme(scheme) => write!(f, "scheme invalid: '{}'", scheme),
+            #[cfg(feature = "alloc")]
+            Error::Type {
+                actual_kind,
+                actual_alg,
+                expected_kind,
+                expected_alg,
+            } => write!(
+                f,
+                "type invalid: {} {} (expected {} {})",
+                actual_alg, actual_kind, expected_alg, expected_kind
+            ),
+            Error::Verification => write!(f, "verification failed"),
         }
     }
 }
@@ -1,33 +1,184 @@
 //! Digest types
 
+macro_rules! impl_hash {
+    ($name:ident, $size:expr, $alg:expr) => {
+        impl TryFrom<&[u8]> for $name {
+            type Error = Error;
+
+            fn try_from(slice: &[u8]) -> Result<Self, Error> {
+                slice.try_into().map($name).map_err(|_| Error::Length {
+                    actual: slice.len(),
+                    expected: $size,
+                })
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0[..]
+            }
+        }
+
+        impl_constant_time_eq!($name);
+        impl_encodable_hash!($name, $alg);
+    };
+}
+
+/// BLAKE2 hash functions
+mod blake2;
+
+/// BLAKE3 hash function
+mod blake3;
+
 /// NIST SHA-2 family of hash functions
 mod sha2;
 
-pub use self::sha2::Sha256Hash;
+/// NIST SHA-3 family of hash functions
+mod sha3;
 
-use crate::algorithm::SHA256_ALG_ID;
-use crate::{encoding::Encodable, error::Error};
-use std::convert::TryInto;
+pub use self::{
+    blake2::Blake2bHash,
+    blake3::Blake3Hash,
+    sha2::{Sha256Hash, Sha384Hash, Sha512Hash, Sha512t256Hash},
+    sha3::Sha3_256Hash,
+};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::algorithm::{
+        self, BLAKE2B_ALG_ID, BLAKE3_ALG_ID, SHA256_ALG_ID, SHA384_ALG_ID, SHA3_256_ALG_ID,
+        SHA512T256_ALG_ID, SHA512_ALG_ID,
+    },
+    crate::{encoding::Encodable, error::Error, kind::Kind, plugin::Custom, CryptoUri},
+    alloc::{borrow::ToOwned, string::String},
+    core::convert::TryInto,
+    subtle::{Choice, ConstantTimeEq},
+};
 
 /// Digest (i.e. hash) algorithms
+#[cfg(feature = "alloc")]
 pub enum Hash {
     /// NIST SHA-2 with a 256-bit digest
     Sha256(Sha256Hash),
+
+    /// NIST SHA-2 with a 384-bit digest
+    Sha384(Sha384Hash),
+
+    /// NIST SHA-2 with a 512-bit digest
+    Sha512(Sha512Hash),
+
+    /// NIST SHA-512 truncated to a 256-bit digest
+    Sha512t256(Sha512t256Hash),
+
+    /// NIST SHA-3 with a 256-bit digest
+    Sha3_256(Sha3_256Hash),
+
+    /// BLAKE2b with a 512-bit digest
+    Blake2b(Blake2bHash),
+
+    /// BLAKE3 with a 256-bit digest
+    Blake3(Blake3Hash),
+
+    /// Digest using an algorithm provided by a plugin
+    Custom(Custom),
 }
 
+#[cfg(feature = "alloc")]
 impl Hash {
+    /// Parse a `Hash` from a `crypto:hash:` URI (ignoring its fragment), returning
+    /// `Error::Kind` if the URI is of a different kind
+    pub fn from_uri_str(uri: &str) -> Result<Self, Error> {
+        CryptoUri::parse_uri_of_kind(uri, Kind::Hash)?.try_into()
+    }
+
     /// Create a new `Digest` for the given algorithm
     pub fn new(alg: &str, bytes: &[u8]) -> Result<Self, Error> {
+        if algorithm::lookup(alg).is_none() {
+            return Ok(Hash::Custom(Custom::new(Kind::Hash, alg, bytes)?));
+        }
+
+        algorithm::validate(alg, Kind::Hash, bytes.len())?;
+
+        Ok(match alg {
+            SHA256_ALG_ID => Hash::Sha256(bytes.try_into()?),
+            SHA384_ALG_ID => Hash::Sha384(bytes.try_into()?),
+            SHA512_ALG_ID => Hash::Sha512(bytes.try_into()?),
+            SHA512T256_ALG_ID => Hash::Sha512t256(bytes.try_into()?),
+            SHA3_256_ALG_ID => Hash::Sha3_256(bytes.try_into()?),
+            BLAKE2B_ALG_ID => Hash::Blake2b(bytes.try_into()?),
+            BLAKE3_ALG_ID => Hash::Blake3(bytes.try_into()?),
+            _ => return Err(Error::Algorithm(alg.to_owned())),
+        })
+    }
+
+    /// Compute the `Hash` of the given data using the given algorithm.
+    ///
+    /// Each algorithm is only available when its corresponding cargo feature
+    /// is enabled (e.g. `sha2`), otherwise `Error::Algorithm` is returned.
+    #[cfg_attr(
+        not(any(
+            feature = "blake2",
+            feature = "blake3",
+            feature = "sha2",
+            feature = "sha3"
+        )),
+        allow(unused_variables)
+    )]
+    pub fn compute(alg: &str, data: &[u8]) -> Result<Self, Error> {
         match alg {
-            SHA256_ALG_ID => Ok(Hash::Sha256(bytes.try_into()?)),
+            #[cfg(feature = "sha2")]
+            SHA256_ALG_ID => Ok(Hash::Sha256(Sha256Hash::digest(data))),
+            #[cfg(feature = "sha2")]
+            SHA384_ALG_ID => Ok(Hash::Sha384(Sha384Hash::digest(data))),
+            #[cfg(feature = "sha2")]
+            SHA512_ALG_ID => Ok(Hash::Sha512(Sha512Hash::digest(data))),
+            #[cfg(feature = "sha2")]
+            SHA512T256_ALG_ID => Ok(Hash::Sha512t256(Sha512t256Hash::digest(data))),
+            #[cfg(feature = "sha3")]
+            SHA3_256_ALG_ID => Ok(Hash::Sha3_256(Sha3_256Hash::digest(data))),
+            #[cfg(feature = "blake2")]
+            BLAKE2B_ALG_ID => Ok(Hash::Blake2b(Blake2bHash::digest(data))),
+            #[cfg(feature = "blake3")]
+            BLAKE3_ALG_ID => Ok(Hash::Blake3(Blake3Hash::digest(data))),
             _ => Err(Error::Algorithm(alg.to_owned())),
         }
     }
 
+    /// Verify that this `Hash` is the digest of the given data
+    pub fn verify(&self, data: &[u8]) -> Result<(), Error> {
+        if Hash::compute(self.alg_id(), data)?.ct_eq(self).into() {
+            Ok(())
+        } else {
+            Err(Error::Verification)
+        }
+    }
+
+    /// Compare this `Hash` with another in constant time (with respect to
+    /// their digests; the algorithms are compared in variable time)
+    pub fn ct_eq(&self, other: &Hash) -> Choice {
+        Choice::from(u8::from(self.alg_id() == other.alg_id()))
+            & self.as_ref().ct_eq(other.as_ref())
+    }
+
+    /// Get the algorithm identifier for this `Hash`
+    pub fn alg_id(&self) -> &'static str {
+        match self {
+            Hash::Sha256(_) => SHA256_ALG_ID,
+            Hash::Sha384(_) => SHA384_ALG_ID,
+            Hash::Sha512(_) => SHA512_ALG_ID,
+            Hash::Sha512t256(_) => SHA512T256_ALG_ID,
+            Hash::Sha3_256(_) => SHA3_256_ALG_ID,
+            Hash::Blake2b(_) => BLAKE2B_ALG_ID,
+            Hash::Blake3(_) => BLAKE3_ALG_ID,
+            Hash::Custom(ref custom) => custom.alg_id(),
+        }
+    }
+
     /// Return a `Sha256Digest` if the underlying digest is SHA-256
     pub fn sha256_digest(&self) -> Option<&Sha256Hash> {
         match self {
             Hash::Sha256(ref digest) => Some(digest),
+            _ => None,
         }
     }
 
@@ -35,13 +186,159 @@ impl Hash {
     pub fn is_sha256_digest(&self) -> bool {
         self.sha256_digest().is_some()
     }
+
+    /// Return a `Sha384Hash` if the underlying digest is SHA-384
+    pub fn sha384_digest(&self) -> Option<&Sha384Hash> {
+        match self {
+            Hash::Sha384(ref digest) => Some(digest),
+            _ => None,
+        }
+    }
+
+    /// Is this Digest a SHA-384 digest?
+    pub fn is_sha384_digest(&self) -> bool {
+        self.sha384_digest().is_some()
+    }
+
+    /// Return a `Sha512Hash` if the underlying digest is SHA-512
+    pub fn sha512_digest(&self) -> Option<&Sha512Hash> {
+        match self {
+            Hash::Sha512(ref digest) => Some(digest),
+            _ => None,
+        }
+    }
+
+    /// Is this Digest a SHA-512 digest?
+    pub fn is_sha512_digest(&self) -> bool {
+        self.sha512_digest().is_some()
+    }
+
+    /// Return a `Sha512t256Hash` if the underlying digest is SHA-512/256
+    pub fn sha512t256_digest(&self) -> Option<&Sha512t256Hash> {
+        match self {
+            Hash::Sha512t256(ref digest) => Some(digest),
+            _ => None,
+        }
+    }
+
+    /// Is this Digest a SHA-512/256 digest?
+    pub fn is_sha512t256_digest(&self) -> bool {
+        self.sha512t256_digest().is_some()
+    }
+
+    /// Return a `Sha3_256Hash` if the underlying digest is SHA3-256
+    pub fn sha3_256_digest(&self) -> Option<&Sha3_256Hash> {
+        match self {
+            Hash::Sha3_256(ref digest) => Some(digest),
+            _ => None,
+        }
+    }
+
+    /// Is this Digest a SHA3-256 digest?
+    pub fn is_sha3_256_digest(&self) -> bool {
+        self.sha3_256_digest().is_some()
+    }
+
+    /// Return a `Blake2bHash` if the underlying digest is BLAKE2b
+    pub fn blake2b_digest(&self) -> Option<&Blake2bHash> {
+        match self {
+            Hash::Blake2b(ref digest) => Some(digest),
+            _ => None,
+        }
+    }
+
+    /// Is this Digest a BLAKE2b digest?
+    pub fn is_blake2b_digest(&self) -> bool {
+        self.blake2b_digest().is_some()
+    }
+
+    /// Return a `Blake3Hash` if the underlying digest is BLAKE3
+    pub fn blake3_digest(&self) -> Option<&Blake3Hash> {
+        match self {
+            Hash::Blake3(ref digest) => Some(digest),
+            _ => None,
+        }
+    }
+
+    /// Is this Digest a BLAKE3 digest?
+    pub fn is_blake3_digest(&self) -> bool {
+        self.blake3_digest().is_some()
+    }
+
+    /// Return a `Custom` digest if the underlying algorithm is provided by a
+    /// plugin
+    pub fn custom(&self) -> Option<&Custom> {
+        match self {
+            Hash::Custom(ref custom) => Some(custom),
+            _ => None,
+        }
+    }
+
+    /// Is this `Hash` provided by a plugin?
+    pub fn is_custom(&self) -> bool {
+        self.custom().is_some()
+    }
+}
+
+impl_try_from_crypto_uri!(Sha256Hash, Hash, Hash::Sha256, SHA256_ALG_ID);
+impl_try_from_crypto_uri!(Sha384Hash, Hash, Hash::Sha384, SHA384_ALG_ID);
+impl_try_from_crypto_uri!(Sha512Hash, Hash, Hash::Sha512, SHA512_ALG_ID);
+impl_try_from_crypto_uri!(Sha512t256Hash, Hash, Hash::Sha512t256, SHA512T256_ALG_ID);
+impl_try_from_crypto_uri!(Sha3_256Hash, Hash, Hash::Sha3_256, SHA3_256_ALG_ID);
+impl_try_from_crypto_uri!(Blake2bHash, Hash, Hash::Blake2b, BLAKE2B_ALG_ID);
+impl_try_from_crypto_uri!(Blake3Hash, Hash, Hash::Blake3, BLAKE3_ALG_ID);
+
+#[cfg(feature = "alloc")]
+impl_try_from_crypto_uri_kind!(Hash);
+#[cfg(feature = "alloc")]
+impl_hex_base64!(Hash);
+
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for Hash {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Hash::Sha256(ref digest) => digest.as_ref(),
+            Hash::Sha384(ref digest) => digest.as_ref(),
+            Hash::Sha512(ref digest) => digest.as_ref(),
+            Hash::Sha512t256(ref digest) => digest.as_ref(),
+            Hash::Sha3_256(ref digest) => digest.as_ref(),
+            Hash::Blake2b(ref digest) => digest.as_ref(),
+            Hash::Blake3(ref digest) => digest.as_ref(),
+            Hash::Custom(ref custom) => custom.as_ref(),
+        }
+    }
 }
 
+#[cfg(feature = "alloc")]
+impl ConstantTimeEq for Hash {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Hash::ct_eq(self, other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for Hash {
+    fn eq(&self, other: &Self) -> bool {
+        Hash::ct_eq(self, other).into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for Hash {}
+
+#[cfg(feature = "alloc")]
 impl Encodable for Hash {
     /// Serialize this `Digest` as a URI-encoded `String`
     fn to_uri_string(&self) -> String {
         match self {
             Hash::Sha256(ref digest) => digest.to_uri_string(),
+            Hash::Sha384(ref digest) => digest.to_uri_string(),
+            Hash::Sha512(ref digest) => digest.to_uri_string(),
+            Hash::Sha512t256(ref digest) => digest.to_uri_string(),
+            Hash::Sha3_256(ref digest) => digest.to_uri_string(),
+            Hash::Blake2b(ref digest) => digest.to_uri_string(),
+            Hash::Blake3(ref digest) => digest.to_uri_string(),
+            Hash::Custom(ref custom) => custom.to_uri_string(),
         }
     }
 
@@ -49,6 +346,27 @@ impl Encodable for Hash {
     fn to_dasherized_string(&self) -> String {
         match self {
             Hash::Sha256(ref digest) => digest.to_dasherized_string(),
+            Hash::Sha384(ref digest) => digest.to_dasherized_string(),
+            Hash::Sha512(ref digest) => digest.to_dasherized_string(),
+            Hash::Sha512t256(ref digest) => digest.to_dasherized_string(),
+            Hash::Sha3_256(ref digest) => digest.to_dasherized_string(),
+            Hash::Blake2b(ref digest) => digest.to_dasherized_string(),
+            Hash::Blake3(ref digest) => digest.to_dasherized_string(),
+            Hash::Custom(ref custom) => custom.to_dasherized_string(),
+        }
+    }
+
+    /// Serialize this `Digest` as a URI-encoded string into the given buffer
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+        match self {
+            Hash::Sha256(ref digest) => digest.encode_into(buf),
+            Hash::Sha384(ref digest) => digest.encode_into(buf),
+            Hash::Sha512(ref digest) => digest.encode_into(buf),
+            Hash::Sha512t256(ref digest) => digest.encode_into(buf),
+            Hash::Sha3_256(ref digest) => digest.encode_into(buf),
+            Hash::Blake2b(ref digest) => digest.encode_into(buf),
+            Hash::Blake3(ref digest) => digest.encode_into(buf),
+            Hash::Custom(ref custom) => custom.encode_into(buf),
         }
     }
 }
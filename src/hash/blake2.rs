@@ -0,0 +1,22 @@
+//! BLAKE2 hash types
+
+use crate::{algorithm::BLAKE2B_ALG_ID, error::Error};
+
+/// Size of a BLAKE2b hash
+pub const BLAKE2B_HASH_SIZE: usize = 64;
+
+/// BLAKE2b-512 hashes
+pub struct Blake2bHash(pub [u8; BLAKE2B_HASH_SIZE]);
+
+impl_hash!(Blake2bHash, BLAKE2B_HASH_SIZE, BLAKE2B_ALG_ID);
+
+#[cfg(feature = "blake2")]
+impl Blake2bHash {
+    /// Compute the BLAKE2b-512 digest of the given data
+    pub fn digest(data: &[u8]) -> Self {
+        use blake2::Digest;
+        let mut bytes = [0u8; BLAKE2B_HASH_SIZE];
+        bytes.copy_from_slice(&blake2::Blake2b512::digest(data));
+        Blake2bHash(bytes)
+    }
+}
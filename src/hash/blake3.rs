@@ -0,0 +1,19 @@
+//! BLAKE3 hash types
+
+use crate::{algorithm::BLAKE3_ALG_ID, error::Error};
+
+/// Size of a BLAKE3 hash
+pub const BLAKE3_HASH_SIZE: usize = 32;
+
+/// BLAKE3 hashes (default 256-bit output)
+pub struct Blake3Hash(pub [u8; BLAKE3_HASH_SIZE]);
+
+impl_hash!(Blake3Hash, BLAKE3_HASH_SIZE, BLAKE3_ALG_ID);
+
+#[cfg(feature = "blake3")]
+impl Blake3Hash {
+    /// Compute the BLAKE3 digest of the given data
+    pub fn digest(data: &[u8]) -> Self {
+        Blake3Hash(blake3::hash(data).into())
+    }
+}
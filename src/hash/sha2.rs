@@ -1,28 +1,59 @@
 //! SHA2 hash types
 
-use crate::{algorithm::SHA256_ALG_ID, error::Error};
+use crate::{
+    algorithm::{SHA256_ALG_ID, SHA384_ALG_ID, SHA512T256_ALG_ID, SHA512_ALG_ID},
+    error::Error,
+};
 
 /// Size of a SHA-256 hash
 pub const SHA256_HASH_SIZE: usize = 32;
 
+/// Size of a SHA-384 hash
+pub const SHA384_HASH_SIZE: usize = 48;
+
+/// Size of a SHA-512 hash
+pub const SHA512_HASH_SIZE: usize = 64;
+
+/// Size of a SHA-512/256 hash
+pub const SHA512T256_HASH_SIZE: usize = 32;
+
 /// NIST SHA-256 hashes
 pub struct Sha256Hash(pub [u8; SHA256_HASH_SIZE]);
 
-impl TryFrom<&[u8]> for Sha256Hash {
-    type Error = Error;
+/// NIST SHA-384 hashes
+pub struct Sha384Hash(pub [u8; SHA384_HASH_SIZE]);
 
-    fn try_from(slice: &[u8]) -> Result<Self, Error> {
-        slice.try_into().map(Sha256Hash).map_err(|_| Error::Length {
-            actual: slice.len(),
-            expected: SHA256_HASH_SIZE,
-        })
-    }
-}
+/// NIST SHA-512 hashes
+pub struct Sha512Hash(pub [u8; SHA512_HASH_SIZE]);
+
+/// NIST SHA-512/256 hashes
+pub struct Sha512t256Hash(pub [u8; SHA512T256_HASH_SIZE]);
+
+impl_hash!(Sha256Hash, SHA256_HASH_SIZE, SHA256_ALG_ID);
+impl_hash!(Sha384Hash, SHA384_HASH_SIZE, SHA384_ALG_ID);
+impl_hash!(Sha512Hash, SHA512_HASH_SIZE, SHA512_ALG_ID);
+impl_hash!(Sha512t256Hash, SHA512T256_HASH_SIZE, SHA512T256_ALG_ID);
 
-impl AsRef<[u8]> for Sha256Hash {
-    fn as_ref(&self) -> &[u8] {
-        &self.0[..]
-    }
+#[cfg(feature = "sha2")]
+macro_rules! impl_sha2_digest {
+    ($name:ident, $hasher:ty, $size:expr) => {
+        impl $name {
+            /// Compute the digest of the given data
+            pub fn digest(data: &[u8]) -> Self {
+                use sha2::Digest;
+                let mut bytes = [0u8; $size];
+                bytes.copy_from_slice(&<$hasher>::digest(data));
+                $name(bytes)
+            }
+        }
+    };
 }
 
-impl_encodable_hash!(Sha256Hash, SHA256_ALG_ID);
+#[cfg(feature = "sha2")]
+impl_sha2_digest!(Sha256Hash, sha2::Sha256, SHA256_HASH_SIZE);
+#[cfg(feature = "sha2")]
+impl_sha2_digest!(Sha384Hash, sha2::Sha384, SHA384_HASH_SIZE);
+#[cfg(feature = "sha2")]
+impl_sha2_digest!(Sha512Hash, sha2::Sha512, SHA512_HASH_SIZE);
+#[cfg(feature = "sha2")]
+impl_sha2_digest!(Sha512t256Hash, sha2::Sha512_256, SHA512T256_HASH_SIZE);
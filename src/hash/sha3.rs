@@ -0,0 +1,20 @@
+//! SHA3 hash types
+
+use crate::{algorithm::SHA3_256_ALG_ID, error::Error};
+
+/// Size of a SHA3-256 hash
+pub const SHA3_256_HASH_SIZE: usize = 32;
+
+/// NIST SHA3-256 hashes
+pub struct Sha3_256Hash(pub [u8; SHA3_256_HASH_SIZE]);
+
+impl_hash!(Sha3_256Hash, SHA3_256_HASH_SIZE, SHA3_256_ALG_ID);
+
+#[cfg(feature = "sha3")]
+impl Sha3_256Hash {
+    /// Compute the SHA3-256 digest of the given data
+    pub fn digest(data: &[u8]) -> Self {
+        use sha3::Digest;
+        Sha3_256Hash(sha3::Sha3_256::digest(data).into())
+    }
+}
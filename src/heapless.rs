@@ -0,0 +1,290 @@
+//! Allocation-free Bech32 encoding and decoding
+//!
+//! Used by [`Encodable::encode_into`][crate::Encodable::encode_into] and the
+//! `parse_uri`/`parse_dasherized` functions of fixed-size types (e.g.
+//! [`Ed25519PublicKey::parse_uri`][crate::public_key::Ed25519PublicKey::parse_uri]),
+//! which never use the heap. As the error types which carry strings would
+//! need to allocate, these report `Error::Parse` instead.
+
+use crate::{encoding::Encoding, error::Error};
+use zeroize::Zeroize;
+
+/// Bech32 character set
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Size of the Bech32 checksum in characters
+const CHECKSUM_SIZE: usize = 6;
+
+/// Bech32 checksum generator polynomial coefficients
+const GENERATOR: [u32; 5] = [
+    0x3b6a_57b2,
+    0x2650_8e6d,
+    0x1ea1_19fa,
+    0x3d42_33dd,
+    0x2a14_62b3,
+];
+
+/// Writes a Bech32 string into a buffer, starting with its human-readable
+/// part (which may be written in several pieces)
+pub(crate) struct Encoder<'a> {
+    /// Output buffer
+    buf: &'a mut [u8],
+
+    /// Length of the human-readable part written so far (which may exceed
+    /// the size of the buffer, in which case encoding will fail)
+    hrp_len: usize,
+}
+
+impl<'a> Encoder<'a> {
+    /// Create a new encoder which writes into the given buffer
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, hrp_len: 0 }
+    }
+
+    /// Append to the human-readable part
+    pub(crate) fn push(&mut self, s: &str) {
+        let end = self.hrp_len + s.len();
+
+        if let Some(out) = self.buf.get_mut(self.hrp_len..end) {
+            out.copy_from_slice(s.as_bytes());
+        }
+
+        self.hrp_len = end;
+    }
+
+    /// Append a character to the human-readable part
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    pub(crate) fn push_char(&mut self, c: char) {
+        self.push(c.encode_utf8(&mut [0u8; 4]));
+    }
+
+    /// Write the delimiter, data and checksum, returning the encoded string
+    pub(crate) fn finish(self, delimiter: char, data: &[u8]) -> Result<&'a str, Error> {
+        let hrp_len = self.hrp_len;
+        let len = encoded_len(hrp_len, data.len());
+
+        if len > self.buf.len() {
+            return Err(Error::Length {
+                actual: self.buf.len(),
+                expected: len,
+            });
+        }
+
+        let mut checksum = hrp_checksum(&self.buf[..hrp_len])?;
+        let mut pos = hrp_len;
+        self.buf[pos] = delimiter as u8;
+        pos += 1;
+
+        let mut acc = 0u32;
+        let mut bits = 0u32;
+
+        for &byte in data {
+            acc = (acc << 8) | u32::from(byte);
+            bits += 8;
+
+            while bits >= 5 {
+                bits -= 5;
+                let value = ((acc >> bits) & 31) as u8;
+                checksum = polymod_step(checksum, value);
+                self.buf[pos] = CHARSET[usize::from(value)];
+                pos += 1;
+            }
+        }
+
+        if bits > 0 {
+            let value = ((acc << (5 - bits)) & 31) as u8;
+            checksum = polymod_step(checksum, value);
+            self.buf[pos] = CHARSET[usize::from(value)];
+            pos += 1;
+        }
+
+        acc.zeroize();
+
+        for _ in 0..CHECKSUM_SIZE {
+            checksum = polymod_step(checksum, 0);
+        }
+
+        checksum ^= 1;
+
+        for i in 0..CHECKSUM_SIZE {
+            let value = (checksum >> (5 * (CHECKSUM_SIZE - 1 - i))) & 31;
+            self.buf[pos] = CHARSET[value as usize];
+            pos += 1;
+        }
+
+        core::str::from_utf8(&self.buf[..pos]).map_err(|_| Error::Parse)
+    }
+}
+
+/// Get the length of a Bech32 string with a human-readable part of the given
+/// length encoding the given amount of data
+pub(crate) fn encoded_len(hrp_len: usize, data_len: usize) -> usize {
+    hrp_len + 1 + (data_len * 8).div_ceil(5) + CHECKSUM_SIZE
+}
+
+/// Decode a CryptoURI in the given encoding with the given scheme and
+/// algorithm into `out`, which must be exactly the size of its data. Any
+/// fragment is ignored.
+pub(crate) fn decode_prefixed(
+    uri: &str,
+    encoding: &Encoding,
+    scheme: &str,
+    alg_id: &str,
+    out: &mut [u8],
+) -> Result<(), Error> {
+    let hrp = decode(strip_fragment(uri, encoding), encoding.delimiter, out)?;
+
+    let valid = hrp.len() == scheme.len() + alg_id.len()
+        && hrp.is_char_boundary(scheme.len())
+        && hrp[..scheme.len()].eq_ignore_ascii_case(scheme)
+        && hrp[scheme.len()..].eq_ignore_ascii_case(alg_id);
+
+    if !valid {
+        out.zeroize();
+        return Err(Error::Parse);
+    }
+
+    Ok(())
+}
+
+/// Split a CryptoURI into the part covered by the Bech32 checksum and its
+/// fragment (if any)
+pub(crate) fn split_fragment<'a>(uri: &'a str, encoding: &Encoding) -> (&'a str, Option<&'a str>) {
+    match encoding.fragment_delimiter.and_then(|d| uri.find(d)) {
+        Some(pos) => (&uri[..pos], Some(&uri[(pos + 1)..])),
+        None => (uri, None),
+    }
+}
+
+/// Remove the fragment from a CryptoURI (if it has one)
+fn strip_fragment<'a>(uri: &'a str, encoding: &Encoding) -> &'a str {
+    split_fragment(uri, encoding).0
+}
+
+/// Decode a Bech32 string into `out`, which must be exactly the size of the
+/// data, returning its human-readable part
+pub(crate) fn decode<'a>(
+    encoded: &'a str,
+    delimiter: char,
+    out: &mut [u8],
+) -> Result<&'a str, Error> {
+    let (hrp, payload) = verify(encoded, delimiter)?;
+    decode_payload(payload, out)?;
+    Ok(hrp)
+}
+
+/// Verify the checksum and padding of a Bech32 string without decoding it,
+/// returning its human-readable part and payload (i.e. the data characters
+/// excluding the checksum)
+pub(crate) fn verify(encoded: &str, delimiter: char) -> Result<(&str, &str), Error> {
+    let pos = encoded.rfind(delimiter).ok_or(Error::Parse)?;
+    let hrp = &encoded[..pos];
+    let data = &encoded[(pos + 1)..];
+
+    if hrp.is_empty() || data.len() < CHECKSUM_SIZE {
+        return Err(Error::Parse);
+    }
+
+    let mut checksum = hrp_checksum(hrp.as_bytes())?;
+
+    for c in data.bytes() {
+        checksum = polymod_step(checksum, char_value(c)?);
+    }
+
+    if checksum != 1 {
+        return Err(Error::Checksum);
+    }
+
+    let payload = &data[..(data.len() - CHECKSUM_SIZE)];
+
+    // Padding must be less than a character and all zeroes
+    let padding_bits = (payload.len() * 5) % 8;
+
+    if let Some(last) = payload.bytes().last() {
+        if padding_bits >= 5 || char_value(last)? & ((1 << padding_bits) - 1) != 0 {
+            return Err(Error::Parse);
+        }
+    }
+
+    Ok((hrp, payload))
+}
+
+/// Get the length of the data encoded by a verified Bech32 payload
+pub(crate) fn payload_len(payload: &str) -> usize {
+    payload.len() * 5 / 8
+}
+
+/// Decode a verified Bech32 payload into `out`, which must be exactly the
+/// size of the data
+pub(crate) fn decode_payload(payload: &str, out: &mut [u8]) -> Result<(), Error> {
+    let len = payload_len(payload);
+
+    if len != out.len() {
+        return Err(Error::Length {
+            actual: len,
+            expected: out.len(),
+        });
+    }
+
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    let mut pos = 0;
+
+    for c in payload.bytes() {
+        acc = (acc << 5) | u32::from(char_value(c)?);
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            out[pos] = (acc >> bits) as u8;
+            pos += 1;
+        }
+    }
+
+    acc.zeroize();
+    Ok(())
+}
+
+/// Get the 5-bit value of a Bech32 character
+fn char_value(c: u8) -> Result<u8, Error> {
+    CHARSET
+        .iter()
+        .position(|&x| x == c)
+        .map(|value| value as u8)
+        .ok_or(Error::Parse)
+}
+
+/// Compute the checksum state after the given human-readable part
+fn hrp_checksum(hrp: &[u8]) -> Result<u32, Error> {
+    if hrp.iter().any(|c| !(33..=126).contains(c)) {
+        return Err(Error::Parse);
+    }
+
+    let mut checksum = 1;
+
+    for c in hrp {
+        checksum = polymod_step(checksum, c.to_ascii_lowercase() >> 5);
+    }
+
+    checksum = polymod_step(checksum, 0);
+
+    for c in hrp {
+        checksum = polymod_step(checksum, c.to_ascii_lowercase() & 31);
+    }
+
+    Ok(checksum)
+}
+
+/// Update a Bech32 checksum with a 5-bit value
+fn polymod_step(checksum: u32, value: u8) -> u32 {
+    let top = checksum >> 25;
+    let mut checksum = ((checksum & 0x01ff_ffff) << 5) ^ u32::from(value);
+
+    for (i, generator) in GENERATOR.iter().enumerate() {
+        if (top >> i) & 1 == 1 {
+            checksum ^= generator;
+        }
+    }
+
+    checksum
+}
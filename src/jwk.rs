@@ -0,0 +1,302 @@
+//! JSON Web Key (RFC 7517) conversions for keys
+//!
+//! Ed25519 and X25519 keys use the `OKP` key type (RFC 8037), identified by
+//! their `crv`. Symmetric keys use the `oct` key type, with their CryptoURI
+//! algorithm identifier stored in the `cryptouri_alg` member, as most have
+//! no JOSE equivalent.
+//!
+//! The `alg` member is only included where JOSE defines a key `alg` for the
+//! algorithm: the JWS algorithm name for MAC and signature keys (from the
+//! [`algorithm`] registry), or `dir` for AEAD keys (i.e. direct use as a JWE
+//! content encryption key). Public keys are given a `kid` of their
+//! [`PublicKey::fingerprint`], and a CryptoURI's fragment is stored in the
+//! `fragment` member.
+
+use crate::{
+    algorithm::{self, Category, Info, ED25519_ALG_ID, X25519_ALG_ID},
+    error::Error,
+    kind::Kind,
+    public_key::PublicKey,
+    secret_key::SecretKey,
+    CryptoUri, CryptoUriKind, Encodable,
+};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use base64ct::{Base64UrlUnpadded, Encoding};
+use serde_json::{Map, Value};
+use zeroize::{Zeroize, Zeroizing};
+
+/// JWK members
+type Members = Map<String, Value>;
+
+/// `crv` parameter values for `OKP` keys
+const OKP_CURVES: &[(&str, &str)] = &[(ED25519_ALG_ID, "Ed25519"), (X25519_ALG_ID, "X25519")];
+
+/// JWK member containing the CryptoURI algorithm identifier of `oct` keys
+const ALGORITHM_MEMBER: &str = "cryptouri_alg";
+
+/// JWK member containing the CryptoURI fragment
+const FRAGMENT_MEMBER: &str = "fragment";
+
+/// JWK members containing secret key material
+const SECRET_MEMBERS: &[&str] = &["d", "k"];
+
+impl PublicKey {
+    /// Decode a `PublicKey` from a JSON Web Key
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let mut members = parse(jwk)?;
+        let result = public_key_from_members(&members);
+        zeroize_secret_members(&mut members);
+        result
+    }
+
+    /// Encode this `PublicKey` as a JSON Web Key
+    pub fn to_jwk(&self) -> Result<String, Error> {
+        Ok(serialize(&public_key_members(self)?))
+    }
+}
+
+impl SecretKey {
+    /// Decode a `SecretKey` from a JSON Web Key
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let mut members = parse(jwk)?;
+        let result = secret_key_from_members(&members);
+        zeroize_secret_members(&mut members);
+        result
+    }
+
+    /// Encode this `SecretKey` as a JSON Web Key. `OKP` private keys include
+    /// the public key, which is derived from the secret key.
+    pub fn to_jwk(&self) -> Result<Zeroizing<String>, Error> {
+        let mut members = secret_key_members(self)?;
+        let jwk = Zeroizing::new(serialize(&members));
+        zeroize_secret_members(&mut members);
+        Ok(jwk)
+    }
+}
+
+impl CryptoUri {
+    /// Decode a public or secret key `CryptoUri` from a JSON Web Key,
+    /// taking its fragment from the `fragment` member (if present)
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let mut members = parse(jwk)?;
+
+        let kind = if SECRET_MEMBERS.iter().any(|&m| members.contains_key(m)) {
+            secret_key_from_members(&members).map(CryptoUriKind::SecretKey)
+        } else {
+            public_key_from_members(&members).map(CryptoUriKind::PublicKey)
+        };
+
+        zeroize_secret_members(&mut members);
+
+        let fragment = match members.get(FRAGMENT_MEMBER) {
+            Some(Value::String(fragment)) => Some(fragment.clone()),
+            Some(_) => return Err(Error::Parse),
+            None => None,
+        };
+
+        Ok(CryptoUri {
+            kind: kind?,
+            fragment,
+        })
+    }
+
+    /// Encode a public or secret key `CryptoUri` as a JSON Web Key,
+    /// storing its fragment in the `fragment` member (if present)
+    pub fn to_jwk(&self) -> Result<Zeroizing<String>, Error> {
+        let mut members = match self.kind() {
+            CryptoUriKind::PublicKey(ref key) => public_key_members(key)?,
+            CryptoUriKind::SecretKey(ref key) => secret_key_members(key)?,
+            _ => return Err(Error::Parse),
+        };
+
+        if let Some(fragment) = self.fragment() {
+            members.insert(FRAGMENT_MEMBER.to_owned(), fragment.into());
+        }
+
+        let jwk = Zeroizing::new(serialize(&members));
+        zeroize_secret_members(&mut members);
+        Ok(jwk)
+    }
+}
+
+/// Parse the members of a JSON Web Key
+fn parse(jwk: &str) -> Result<Members, Error> {
+    match serde_json::from_str(jwk).map_err(|_| Error::Parse)? {
+        Value::Object(members) => Ok(members),
+        _ => Err(Error::Parse),
+    }
+}
+
+/// Serialize the members of a JSON Web Key
+fn serialize(members: &Members) -> String {
+    serde_json::to_string(members).expect("JWK serialization failed")
+}
+
+/// Get the value of a string member of a JSON Web Key
+fn member<'a>(members: &'a Members, name: &str) -> Result<&'a str, Error> {
+    match members.get(name) {
+        Some(Value::String(value)) => Ok(value),
+        _ => Err(Error::Parse),
+    }
+}
+
+/// Get the value of a base64url-encoded member of a JSON Web Key
+fn bytes_member(members: &Members, name: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+    Base64UrlUnpadded::decode_vec(member(members, name)?)
+        .map(Zeroizing::new)
+        .map_err(|_| Error::Parse)
+}
+
+/// Find the algorithm for an `OKP` key with the given `crv`
+fn okp_algorithm(members: &Members) -> Result<&'static str, Error> {
+    let crv = member(members, "crv")?;
+
+    OKP_CURVES
+        .iter()
+        .find(|(_, name)| *name == crv)
+        .map(|(alg_id, _)| *alg_id)
+        .ok_or_else(|| Error::Algorithm(crv.to_owned()))
+}
+
+/// Get the `crv` for the given algorithm, if it can be used in `OKP` keys
+fn okp_curve(alg_id: &str) -> Option<&'static str> {
+    OKP_CURVES
+        .iter()
+        .find(|(id, _)| *id == alg_id)
+        .map(|(_, crv)| *crv)
+}
+
+/// Decode a `PublicKey` from the members of a JSON Web Key
+fn public_key_from_members(members: &Members) -> Result<PublicKey, Error> {
+    if member(members, "kty")? != "OKP" || SECRET_MEMBERS.iter().any(|&m| members.contains_key(m)) {
+        return Err(Error::Parse);
+    }
+
+    PublicKey::new(okp_algorithm(members)?, &bytes_member(members, "x")?)
+}
+
+/// Encode a `PublicKey` as the members of a JSON Web Key
+fn public_key_members(key: &PublicKey) -> Result<Members, Error> {
+    let info =
+        algorithm::lookup(key.alg_id()).ok_or_else(|| Error::Algorithm(key.alg_id().to_owned()))?;
+
+    let crv = okp_curve(info.id).ok_or_else(|| Error::Algorithm(info.id.to_owned()))?;
+
+    let mut members = Members::new();
+    members.insert("kty".to_owned(), "OKP".into());
+    members.insert("crv".to_owned(), crv.into());
+    members.insert(
+        "x".to_owned(),
+        Base64UrlUnpadded::encode_string(key.as_ref()).into(),
+    );
+    members.insert("kid".to_owned(), key.fingerprint().to_uri_string().into());
+    insert_alg(&mut members, info);
+    Ok(members)
+}
+
+/// Decode a `SecretKey` from the members of a JSON Web Key
+fn secret_key_from_members(members: &Members) -> Result<SecretKey, Error> {
+    match member(members, "kty")? {
+        "OKP" => {
+            let key = SecretKey::new(okp_algorithm(members)?, &bytes_member(members, "d")?)?;
+            let public_key = key
+                .public_key()
+                .ok_or_else(|| Error::Algorithm(key.alg_id().to_owned()))?;
+
+            // The public key (`x`) is required, and must match the secret key
+            if bytes_member(members, "x")?.as_slice() != public_key.as_ref() {
+                return Err(Error::Parse);
+            }
+
+            Ok(key)
+        }
+        "oct" => {
+            // Without `cryptouri_alg`, only an `alg` naming a single
+            // algorithm (i.e. a JWS algorithm, not `dir`) identifies it
+            let info = if members.contains_key(ALGORITHM_MEMBER) {
+                let alg_id = member(members, ALGORITHM_MEMBER)?;
+                algorithm::lookup(alg_id).ok_or_else(|| Error::Algorithm(alg_id.to_owned()))?
+            } else {
+                let alg = member(members, "alg")?;
+                algorithm::lookup_jose(alg)
+                    .filter(|info| jwk_alg(info) == Some(alg))
+                    .ok_or_else(|| Error::Algorithm(alg.to_owned()))?
+            };
+
+            if info.public_key_size.is_some() {
+                return Err(Error::Algorithm(info.id.to_owned()));
+            }
+
+            // The `alg` member (if any) must match the algorithm
+            if let Some(alg) = members.get("alg") {
+                if alg.as_str().is_none() || alg.as_str() != jwk_alg(info) {
+                    return Err(Error::Algorithm(info.id.to_owned()));
+                }
+            }
+
+            SecretKey::new(info.id, &bytes_member(members, "k")?)
+        }
+        _ => Err(Error::Parse),
+    }
+}
+
+/// Encode a `SecretKey` as the members of a JSON Web Key
+fn secret_key_members(key: &SecretKey) -> Result<Members, Error> {
+    let info = algorithm::lookup(key.alg_id())
+        .filter(|info| info.supports(Kind::SecretKey))
+        .filter(|_| key.pipeline().is_none())
+        .ok_or_else(|| Error::Algorithm(key.alg_id().to_owned()))?;
+
+    if okp_curve(info.id).is_some() {
+        // `OKP` private keys are the public key's members plus `d`
+        let public_key = key
+            .public_key()
+            .ok_or_else(|| Error::Algorithm(info.id.to_owned()))?;
+
+        let mut members = public_key_members(&public_key)?;
+        members.insert(
+            "d".to_owned(),
+            Base64UrlUnpadded::encode_string(key.as_bytes()).into(),
+        );
+        return Ok(members);
+    }
+
+    if info.public_key_size.is_some() {
+        return Err(Error::Algorithm(info.id.to_owned()));
+    }
+
+    let mut members = Members::new();
+    members.insert("kty".to_owned(), "oct".into());
+    members.insert(
+        "k".to_owned(),
+        Base64UrlUnpadded::encode_string(key.as_bytes()).into(),
+    );
+    members.insert(ALGORITHM_MEMBER.to_owned(), info.id.into());
+    insert_alg(&mut members, info);
+    Ok(members)
+}
+
+/// Get the JWK `alg` for keys of the given algorithm, if JOSE defines one
+fn jwk_alg(info: &Info) -> Option<&'static str> {
+    match info.category {
+        Category::Mac | Category::Signature => info.jose,
+        Category::Aead => Some("dir"),
+        _ => None,
+    }
+}
+
+/// Insert the `alg` member for the given algorithm (if it has one)
+fn insert_alg(members: &mut Members, info: &Info) {
+    if let Some(alg) = jwk_alg(info) {
+        members.insert("alg".to_owned(), alg.into());
+    }
+}
+
+/// Zeroize the members of a JSON Web Key which contain secret key material
+fn zeroize_secret_members(members: &mut Members) {
+    for &name in SECRET_MEMBERS {
+        if let Some(Value::String(value)) = members.get_mut(name) {
+            value.zeroize();
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Key pairs (i.e. an asymmetric secret key and its public key)
+
+#[cfg(feature = "sha2")]
+use crate::hash::Hash;
+use crate::{encoding::Encodable, error::Error, public_key::PublicKey, secret_key::SecretKey};
+use alloc::string::String;
+use zeroize::Zeroizing;
+
+/// Asymmetric secret key paired with the public key derived from it.
+///
+/// Serializes as its secret key: the public key is derived again when
+/// parsing.
+pub struct KeyPair {
+    /// Secret key
+    secret_key: SecretKey,
+
+    /// Public key derived from the secret key
+    public_key: PublicKey,
+}
+
+impl KeyPair {
+    /// Create a `KeyPair` from the given `SecretKey`, deriving its public key.
+    ///
+    /// Returns `Error::Algorithm` if the key isn't asymmetric, or its curve
+    /// implementation isn't enabled.
+    pub fn new(secret_key: SecretKey) -> Result<Self, Error> {
+        let public_key = secret_key
+            .public_key()
+            .ok_or_else(|| Error::Algorithm(secret_key.alg_id().into()))?;
+
+        Ok(Self {
+            secret_key,
+            public_key,
+        })
+    }
+
+    /// Parse a `KeyPair` from a secret key CryptoURI
+    pub fn from_uri_str(uri: &str) -> Result<Self, Error> {
+        Self::new(SecretKey::from_uri_str(uri)?)
+    }
+
+    /// Get the secret key
+    pub fn secret_key(&self) -> &SecretKey {
+        &self.secret_key
+    }
+
+    /// Get the public key
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Compute the fingerprint of the public key (see
+    /// [`PublicKey::fingerprint`])
+    #[cfg(feature = "sha2")]
+    pub fn fingerprint(&self) -> Hash {
+        self.public_key.fingerprint()
+    }
+
+    /// Serialize this `KeyPair` (i.e. its secret key) as a URI-encoded
+    /// string which is wiped when dropped
+    pub fn to_uri_string(&self) -> Zeroizing<String> {
+        self.secret_key.to_uri_string()
+    }
+
+    /// Serialize this `KeyPair` (i.e. its secret key) as a "dasherized"
+    /// string which is wiped when dropped
+    pub fn to_dasherized_string(&self) -> Zeroizing<String> {
+        self.secret_key.to_dasherized_string()
+    }
+}
+
+impl Encodable for KeyPair {
+    /// Serialize this `KeyPair` as its secret key's URI-encoded `String`
+    fn to_uri_string(&self) -> String {
+        Encodable::to_uri_string(&self.secret_key)
+    }
+
+    /// Serialize this `KeyPair` as its secret key's "dasherized" `String`
+    fn to_dasherized_string(&self) -> String {
+        Encodable::to_dasherized_string(&self.secret_key)
+    }
+
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+        self.secret_key.encode_into(buf)
+    }
+}
@@ -0,0 +1,40 @@
+//! Kinds of CryptoURIs
+
+use core::fmt::{self, Display};
+
+/// Kinds of objects which can be encoded as CryptoURIs, i.e. the
+/// `crypto:<kind>:` part of the scheme
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Kind {
+    /// Hashes (`crypto:hash:`)
+    Hash,
+
+    /// Message authentication codes (`crypto:mac:`)
+    Mac,
+
+    /// Public keys (`crypto:pub:key:`)
+    PublicKey,
+
+    /// Secret keys (`crypto:sec:key:`)
+    SecretKey,
+
+    /// Digital signatures (`crypto:sig:`)
+    Signature,
+
+    /// Signed statements, i.e. a hash, a signature over it and the signer
+    /// (`crypto:stmt:`)
+    Statement,
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Kind::Hash => "hash",
+            Kind::Mac => "message authentication code",
+            Kind::PublicKey => "public key",
+            Kind::SecretKey => "secret key",
+            Kind::Signature => "signature",
+            Kind::Statement => "signed statement",
+        })
+    }
+}
@@ -4,31 +4,86 @@
 #![doc(
     html_logo_url = "https://avatars3.githubusercontent.com/u/40766087?u=0267cf8b7fe892bbf35b6114d9eb48adc057d6ff"
 )]
+#![no_std]
 #![forbid(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 #[macro_use]
 mod encoding;
 #[macro_use]
 pub mod error;
 
+#[cfg(feature = "age")]
+mod age;
 pub mod algorithm;
+#[cfg(feature = "alloc")]
+mod binary;
+#[cfg(feature = "cose")]
+mod cose;
 pub mod hash;
+mod heapless;
+#[cfg(feature = "jwk")]
+mod jwk;
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "ed25519-dalek", feature = "x25519-dalek")
+))]
+mod key_pair;
+mod kind;
+pub mod mac;
+#[cfg(feature = "minisign")]
+mod minisign;
+#[cfg(feature = "multibase")]
+pub mod multibase;
+#[cfg(feature = "openssh")]
+mod openssh;
+#[cfg(feature = "alloc")]
 mod parts;
+#[cfg(feature = "pkcs8")]
+mod pkcs8;
+#[cfg(feature = "alloc")]
+pub mod plugin;
 pub mod public_key;
+#[cfg(feature = "alloc")]
 pub mod secret_key;
 pub mod signature;
+#[cfg(feature = "alloc")]
+pub mod statement;
+#[cfg(feature = "alloc")]
+mod unknown;
+#[cfg(feature = "alloc")]
+mod uri_ref;
+
+pub use crate::{encoding::Encodable, error::Error, kind::Kind};
 
+#[cfg(feature = "alloc")]
 pub use crate::{
-    encoding::Encodable, error::Error, hash::Hash, public_key::PublicKey, secret_key::SecretKey,
-    signature::Signature,
+    hash::Hash, mac::Mac, public_key::PublicKey, secret_key::SecretKey, signature::Signature,
+    statement::Statement, unknown::Unknown, uri_ref::CryptoUriRef,
 };
 
-use crate::{
-    encoding::{Encoding, DASHERIZED_ENCODING, URI_ENCODING},
-    parts::Parts,
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "ed25519-dalek", feature = "x25519-dalek")
+))]
+pub use crate::key_pair::KeyPair;
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{
+        encoding::{Encoding, DASHERIZED_ENCODING, URI_ENCODING},
+        parts::Parts,
+    },
+    alloc::{borrow::ToOwned, string::String, vec::Vec},
+    zeroize::Zeroizing,
 };
 
+#[cfg(feature = "alloc")]
 /// `CryptoUri`: URI-based format for encoding cryptographic objects
 pub struct CryptoUri {
     /// Kind of `CryptoUri` (e.g. secret key, public key, hashes, signatures)
@@ -38,11 +93,15 @@ pub struct CryptoUri {
     fragment: Option<String>,
 }
 
+#[cfg(feature = "alloc")]
 /// Kinds of `CryptoUri`s
 pub enum CryptoUriKind {
     /// Hashes (i.e. cryptographic digests)
     Hash(Hash),
 
+    /// Message authentication codes
+    Mac(Mac),
+
     /// Public keys (always asymmetric)
     PublicKey(PublicKey),
 
@@ -51,19 +110,88 @@ pub enum CryptoUriKind {
 
     /// Digital signatures (always asymmetric)
     Signature(Signature),
+
+    /// Signed statements
+    Statement(Statement),
+
+    /// Objects using unknown algorithms (only returned by lenient parsing)
+    Unknown(Unknown),
+}
+
+#[cfg(feature = "alloc")]
+impl CryptoUriKind {
+    /// Get the kind of object
+    pub(crate) fn kind(&self) -> Kind {
+        match self {
+            CryptoUriKind::Hash(_) => Kind::Hash,
+            CryptoUriKind::Mac(_) => Kind::Mac,
+            CryptoUriKind::PublicKey(_) => Kind::PublicKey,
+            CryptoUriKind::SecretKey(_) => Kind::SecretKey,
+            CryptoUriKind::Signature(_) => Kind::Signature,
+            CryptoUriKind::Statement(_) => Kind::Statement,
+            CryptoUriKind::Unknown(ref unknown) => unknown.kind(),
+        }
+    }
+
+    /// Get the algorithm identifier of the object
+    pub(crate) fn alg_id(&self) -> &str {
+        match self {
+            CryptoUriKind::Hash(ref hash) => hash.alg_id(),
+            CryptoUriKind::Mac(ref mac) => mac.alg_id(),
+            CryptoUriKind::PublicKey(ref key) => key.alg_id(),
+            CryptoUriKind::SecretKey(ref key) => key.alg_id(),
+            CryptoUriKind::Signature(ref sig) => sig.alg_id(),
+            CryptoUriKind::Statement(ref statement) => statement.signature().alg_id(),
+            CryptoUriKind::Unknown(ref unknown) => unknown.alg_id(),
+        }
+    }
+
+    /// Create an error reporting that this isn't of the expected type
+    pub(crate) fn type_error(&self, expected_kind: Kind, expected_alg: &'static str) -> Error {
+        Error::Type {
+            actual_kind: self.kind(),
+            actual_alg: self.alg_id().to_owned(),
+            expected_kind,
+            expected_alg,
+        }
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl CryptoUri {
-    /// Parse a `CryptoUri` from a Bech32 encoded string using the given encoding
+    /// Parse a `CryptoUri` from a Bech32 encoded string using the given encoding.
+    ///
+    /// If `lenient` is set, URIs with a valid scheme but an unknown algorithm
+    /// are parsed as `CryptoUriKind::Unknown`.
     // TODO: parser generator rather than handrolling this?
-    fn parse(uri: &str, encoding: &Encoding) -> Result<Self, Error> {
-        let parts = Parts::decode(uri, encoding)?;
+    fn parse(uri: &str, encoding: &Encoding, lenient: bool) -> Result<Self, Error> {
+        Self::from_parts(&Parts::decode(uri, encoding)?, encoding, lenient)
+    }
+
+    /// Create a `CryptoUri` from its decoded parts
+    pub(crate) fn from_parts(
+        parts: &Parts,
+        encoding: &Encoding,
+        lenient: bool,
+    ) -> Result<Self, Error> {
+        let unknown = if lenient {
+            Unknown::from_parts(parts, encoding)
+        } else {
+            None
+        };
 
-        let kind = if parts.prefix.starts_with(encoding.hash_scheme) {
+        let kind = if let Some(unknown) = unknown {
+            CryptoUriKind::Unknown(unknown)
+        } else if parts.prefix.starts_with(encoding.hash_scheme) {
             CryptoUriKind::Hash(Hash::new(
                 &parts.prefix[encoding.hash_scheme.len()..],
                 parts.data.as_ref(),
             )?)
+        } else if parts.prefix.starts_with(encoding.mac_scheme) {
+            CryptoUriKind::Mac(Mac::new(
+                &parts.prefix[encoding.mac_scheme.len()..],
+                parts.data.as_ref(),
+            )?)
         } else if parts.prefix.starts_with(encoding.public_key_scheme) {
             CryptoUriKind::PublicKey(PublicKey::new(
                 &parts.prefix[encoding.public_key_scheme.len()..],
@@ -84,6 +212,12 @@ impl CryptoUri {
                 &parts.prefix[encoding.signature_scheme.len()..],
                 parts.data.as_ref(),
             )?)
+        } else if parts.prefix.starts_with(encoding.statement_scheme) {
+            CryptoUriKind::Statement(Statement::new_combination(
+                &parts.prefix[encoding.statement_scheme.len()..],
+                parts.data.as_ref(),
+                encoding,
+            )?)
         } else {
             return Err(Error::Scheme(parts.prefix.to_owned()));
         };
@@ -96,12 +230,53 @@ impl CryptoUri {
 
     /// Parse a `CryptoUri`
     pub fn parse_uri(uri: &str) -> Result<Self, Error> {
-        Self::parse(uri, URI_ENCODING)
+        Self::parse(uri, URI_ENCODING, false)
     }
 
     /// Parse a `CryptoUri` in URI-embeddable (a.k.a. "dasherized") encoding
     pub fn parse_dasherized(token: &str) -> Result<Self, Error> {
-        Self::parse(token, DASHERIZED_ENCODING)
+        Self::parse(token, DASHERIZED_ENCODING, false)
+    }
+
+    /// Parse a `CryptoUri`, returning an opaque `Unknown` value rather than
+    /// an error if its algorithm is unknown
+    pub fn parse_uri_lenient(uri: &str) -> Result<Self, Error> {
+        Self::parse(uri, URI_ENCODING, true)
+    }
+
+    /// Parse a `CryptoUri` in URI-embeddable (a.k.a. "dasherized") encoding,
+    /// returning an opaque `Unknown` value rather than an error if its
+    /// algorithm is unknown
+    pub fn parse_dasherized_lenient(token: &str) -> Result<Self, Error> {
+        Self::parse(token, DASHERIZED_ENCODING, true)
+    }
+
+    /// Parse a `CryptoUri` as the given concrete type (e.g.
+    /// `Ed25519PublicKey`), returning `Error::Type` if it's of a different
+    /// kind or algorithm
+    pub fn parse_as<T>(uri: &str) -> Result<T, Error>
+    where
+        T: TryFrom<CryptoUri, Error = Error>,
+    {
+        Self::parse_uri(uri)?.try_into()
+    }
+
+    /// Parse a `CryptoUri` of the given kind, returning `Error::Kind` if
+    /// it's of a different kind.
+    ///
+    /// The kind is checked before the data is decoded, so e.g. a secret key
+    /// is never decoded when a public key is expected.
+    pub(crate) fn parse_uri_of_kind(uri: &str, expected: Kind) -> Result<Self, Error> {
+        let uri_ref = CryptoUriRef::parse_uri(uri)?;
+
+        if uri_ref.kind() != expected {
+            return Err(Error::Kind {
+                expected,
+                actual: uri_ref.kind(),
+            });
+        }
+
+        uri_ref.to_crypto_uri()
     }
 
     /// Return the `CryptoUriKind` for this URI
@@ -109,6 +284,11 @@ impl CryptoUri {
         &self.kind
     }
 
+    /// Convert this URI into its `CryptoUriKind`, discarding the fragment
+    pub(crate) fn into_kind(self) -> CryptoUriKind {
+        self.kind
+    }
+
     /// Return a `SecretKey` if the underlying URI is a `crypto:sec:key:`
     pub fn secret_key(&self) -> Option<&SecretKey> {
         match self.kind {
@@ -148,6 +328,19 @@ impl CryptoUri {
         self.hash().is_some()
     }
 
+    /// Return a `Mac` if the underlying URI is a `crypto:mac:`
+    pub fn mac(&self) -> Option<&Mac> {
+        match self.kind {
+            CryptoUriKind::Mac(ref mac) => Some(mac),
+            _ => None,
+        }
+    }
+
+    /// Is this CryptoUri a `crypto:mac:`?
+    pub fn is_mac(&self) -> bool {
+        self.mac().is_some()
+    }
+
     /// Return a `Signature` if the underlying URI is a `crypto:pub:sig:`
     pub fn signature(&self) -> Option<&Signature> {
         match self.kind {
@@ -161,30 +354,125 @@ impl CryptoUri {
         self.signature().is_some()
     }
 
+    /// Return a `Statement` if the underlying URI is a `crypto:stmt:`
+    pub fn statement(&self) -> Option<&Statement> {
+        match self.kind {
+            CryptoUriKind::Statement(ref statement) => Some(statement),
+            _ => None,
+        }
+    }
+
+    /// Is this CryptoUri a `crypto:stmt:`?
+    pub fn is_statement(&self) -> bool {
+        self.statement().is_some()
+    }
+
+    /// Return an `Unknown` if the underlying URI uses an unknown algorithm
+    pub fn unknown(&self) -> Option<&Unknown> {
+        match self.kind {
+            CryptoUriKind::Unknown(ref unknown) => Some(unknown),
+            _ => None,
+        }
+    }
+
+    /// Does this CryptoUri use an unknown algorithm?
+    pub fn is_unknown(&self) -> bool {
+        self.unknown().is_some()
+    }
+
     /// Obtain the fragment for this URI (i.e. everything after `#`)
     pub fn fragment(&self) -> Option<&str> {
         self.fragment.as_ref().map(|fragment| fragment.as_ref())
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Encodable for CryptoUri {
-    /// Serialize this `CryptoUri` as a URI-encoded `String`
+    /// Serialize this `CryptoUri` as a URI-encoded `String`, including its
+    /// fragment (if any)
     fn to_uri_string(&self) -> String {
-        match self.kind {
+        let encoded = match self.kind {
             CryptoUriKind::Hash(ref hash) => hash.to_uri_string(),
+            CryptoUriKind::Mac(ref mac) => mac.to_uri_string(),
             CryptoUriKind::PublicKey(ref pk) => pk.to_uri_string(),
-            CryptoUriKind::SecretKey(ref sk) => sk.to_uri_string(),
+            CryptoUriKind::SecretKey(ref sk) => Encodable::to_uri_string(sk),
             CryptoUriKind::Signature(ref sig) => sig.to_uri_string(),
-        }
+            CryptoUriKind::Statement(ref statement) => statement.to_uri_string(),
+            CryptoUriKind::Unknown(ref unknown) => unknown.to_uri_string(),
+        };
+
+        let (delimiter, fragment) = match (URI_ENCODING.fragment_delimiter, self.fragment()) {
+            (Some(delimiter), Some(fragment)) => (delimiter, fragment),
+            _ => return encoded,
+        };
+
+        // Copy into an exactly sized string (rather than appending, which may
+        // reallocate) so the encoding isn't left behind if it's a secret key
+        let encoded = Zeroizing::new(encoded);
+        let mut uri = String::with_capacity(encoded.len() + 1 + fragment.len());
+        uri.push_str(&encoded);
+        uri.push(delimiter);
+        uri.push_str(fragment);
+        uri
     }
 
     /// Serialize this `CryptoUri` as a "dasherized" `String`
     fn to_dasherized_string(&self) -> String {
         match self.kind {
             CryptoUriKind::Hash(ref hash) => hash.to_dasherized_string(),
+            CryptoUriKind::Mac(ref mac) => mac.to_dasherized_string(),
             CryptoUriKind::PublicKey(ref pk) => pk.to_dasherized_string(),
-            CryptoUriKind::SecretKey(ref sk) => sk.to_dasherized_string(),
+            CryptoUriKind::SecretKey(ref sk) => Encodable::to_dasherized_string(sk),
             CryptoUriKind::Signature(ref sig) => sig.to_dasherized_string(),
+            CryptoUriKind::Statement(ref statement) => statement.to_dasherized_string(),
+            CryptoUriKind::Unknown(ref unknown) => unknown.to_dasherized_string(),
         }
     }
+
+    /// Serialize this `CryptoUri` as a URI-encoded string into the given
+    /// buffer, including its fragment (if any)
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+        let fragment = match (URI_ENCODING.fragment_delimiter, self.fragment()) {
+            (Some(delimiter), Some(fragment)) => Some((delimiter, fragment)),
+            _ => None,
+        };
+
+        let fragment_len = fragment.map(|(_, f)| f.len() + 1).unwrap_or(0);
+
+        let result = match self.kind {
+            CryptoUriKind::Hash(ref hash) => hash.encode_into(buf),
+            CryptoUriKind::Mac(ref mac) => mac.encode_into(buf),
+            CryptoUriKind::PublicKey(ref pk) => pk.encode_into(buf),
+            CryptoUriKind::SecretKey(ref sk) => sk.encode_into(buf),
+            CryptoUriKind::Signature(ref sig) => sig.encode_into(buf),
+            CryptoUriKind::Statement(ref statement) => statement.encode_into(buf),
+            CryptoUriKind::Unknown(ref unknown) => unknown.encode_into(buf),
+        };
+
+        let mut len = match result {
+            Ok(uri) => uri.len(),
+            Err(Error::Length { actual, expected }) => {
+                return Err(Error::Length {
+                    actual,
+                    expected: expected + fragment_len,
+                })
+            }
+            Err(e) => return Err(e),
+        };
+
+        if let Some((delimiter, fragment)) = fragment {
+            if len + fragment_len > buf.len() {
+                return Err(Error::Length {
+                    actual: buf.len(),
+                    expected: len + fragment_len,
+                });
+            }
+
+            buf[len] = delimiter as u8;
+            buf[(len + 1)..(len + fragment_len)].copy_from_slice(fragment.as_bytes());
+            len += fragment_len;
+        }
+
+        core::str::from_utf8(&buf[..len]).map_err(|_| Error::Parse)
+    }
 }
@@ -0,0 +1,184 @@
+//! Message authentication codes
+
+/// Hash-based Message Authentication Codes (RFC 2104)
+mod hmac;
+
+pub use self::hmac::{HmacSha256Tag, HmacSha512Tag};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{
+        algorithm::{self, HMACSHA256_ALG_ID, HMACSHA512_ALG_ID},
+        encoding::Encodable,
+        error::Error,
+        kind::Kind,
+        plugin::Custom,
+        secret_key::SecretKey,
+        CryptoUri,
+    },
+    alloc::{borrow::ToOwned, string::String},
+    core::convert::TryInto,
+};
+
+/// Message authentication code (MAC) algorithms
+#[cfg(feature = "alloc")]
+pub enum Mac {
+    /// HMAC-SHA-256 tag
+    HmacSha256(HmacSha256Tag),
+
+    /// HMAC-SHA-512 tag
+    HmacSha512(HmacSha512Tag),
+
+    /// MAC using an algorithm provided by a plugin
+    Custom(Custom),
+}
+
+#[cfg(feature = "alloc")]
+impl Mac {
+    /// Parse a `Mac` from a `crypto:mac:` URI (ignoring its fragment), returning
+    /// `Error::Kind` if the URI is of a different kind
+    pub fn from_uri_str(uri: &str) -> Result<Self, Error> {
+        CryptoUri::parse_uri_of_kind(uri, Kind::Mac)?.try_into()
+    }
+
+    /// Create a new `Mac` for the given algorithm
+    pub fn new(alg: &str, bytes: &[u8]) -> Result<Self, Error> {
+        if algorithm::lookup(alg).is_none() {
+            return Ok(Mac::Custom(Custom::new(Kind::Mac, alg, bytes)?));
+        }
+
+        algorithm::validate(alg, Kind::Mac, bytes.len())?;
+
+        Ok(match alg {
+            HMACSHA256_ALG_ID => Mac::HmacSha256(bytes.try_into()?),
+            HMACSHA512_ALG_ID => Mac::HmacSha512(bytes.try_into()?),
+            _ => return Err(Error::Algorithm(alg.to_owned())),
+        })
+    }
+
+    /// Compute the `Mac` of the given message using the given `SecretKey`.
+    ///
+    /// Requires the `hmac` cargo feature, otherwise `Error::Algorithm` is
+    /// returned.
+    #[cfg_attr(not(feature = "hmac"), allow(unused_variables))]
+    pub fn compute(key: &SecretKey, msg: &[u8]) -> Result<Self, Error> {
+        match key {
+            #[cfg(feature = "hmac")]
+            SecretKey::HmacSha256(ref key) => Ok(Mac::HmacSha256(key.compute(msg))),
+            #[cfg(feature = "hmac")]
+            SecretKey::HmacSha512(ref key) => Ok(Mac::HmacSha512(key.compute(msg))),
+            _ => Err(Error::Algorithm(key.alg_id().to_owned())),
+        }
+    }
+
+    /// Verify in constant time that this `Mac` is a valid tag for the given
+    /// message under the given `SecretKey`
+    #[cfg_attr(not(feature = "hmac"), allow(unused_variables))]
+    pub fn verify(&self, key: &SecretKey, msg: &[u8]) -> Result<(), Error> {
+        match (self, key) {
+            #[cfg(feature = "hmac")]
+            (Mac::HmacSha256(ref tag), SecretKey::HmacSha256(ref key)) => key.verify(msg, tag),
+            #[cfg(feature = "hmac")]
+            (Mac::HmacSha512(ref tag), SecretKey::HmacSha512(ref key)) => key.verify(msg, tag),
+            _ => Err(Error::Algorithm(key.alg_id().to_owned())),
+        }
+    }
+
+    /// Get the algorithm identifier for this `Mac`
+    pub fn alg_id(&self) -> &'static str {
+        match self {
+            Mac::HmacSha256(_) => HMACSHA256_ALG_ID,
+            Mac::HmacSha512(_) => HMACSHA512_ALG_ID,
+            Mac::Custom(ref custom) => custom.alg_id(),
+        }
+    }
+
+    /// Return an `HmacSha256Tag` if the underlying MAC is HMAC-SHA-256
+    pub fn hmacsha256_tag(&self) -> Option<&HmacSha256Tag> {
+        match self {
+            Mac::HmacSha256(ref tag) => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// Is this `Mac` an HMAC-SHA-256 tag?
+    pub fn is_hmacsha256_tag(&self) -> bool {
+        self.hmacsha256_tag().is_some()
+    }
+
+    /// Return an `HmacSha512Tag` if the underlying MAC is HMAC-SHA-512
+    pub fn hmacsha512_tag(&self) -> Option<&HmacSha512Tag> {
+        match self {
+            Mac::HmacSha512(ref tag) => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// Is this `Mac` an HMAC-SHA-512 tag?
+    pub fn is_hmacsha512_tag(&self) -> bool {
+        self.hmacsha512_tag().is_some()
+    }
+
+    /// Return a `Custom` tag if the underlying algorithm is provided by a
+    /// plugin
+    pub fn custom(&self) -> Option<&Custom> {
+        match self {
+            Mac::Custom(ref custom) => Some(custom),
+            _ => None,
+        }
+    }
+
+    /// Is this `Mac` provided by a plugin?
+    pub fn is_custom(&self) -> bool {
+        self.custom().is_some()
+    }
+}
+
+impl_try_from_crypto_uri!(HmacSha256Tag, Mac, Mac::HmacSha256, HMACSHA256_ALG_ID);
+impl_try_from_crypto_uri!(HmacSha512Tag, Mac, Mac::HmacSha512, HMACSHA512_ALG_ID);
+
+#[cfg(feature = "alloc")]
+impl_try_from_crypto_uri_kind!(Mac);
+#[cfg(feature = "alloc")]
+impl_hex_base64!(Mac);
+
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for Mac {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Mac::HmacSha256(ref tag) => tag.as_ref(),
+            Mac::HmacSha512(ref tag) => tag.as_ref(),
+            Mac::Custom(ref custom) => custom.as_ref(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Encodable for Mac {
+    /// Serialize this `Mac` as a URI-encoded `String`
+    fn to_uri_string(&self) -> String {
+        match self {
+            Mac::HmacSha256(ref tag) => tag.to_uri_string(),
+            Mac::HmacSha512(ref tag) => tag.to_uri_string(),
+            Mac::Custom(ref custom) => custom.to_uri_string(),
+        }
+    }
+
+    /// Serialize this `Mac` as a "dasherized" `String`
+    fn to_dasherized_string(&self) -> String {
+        match self {
+            Mac::HmacSha256(ref tag) => tag.to_dasherized_string(),
+            Mac::HmacSha512(ref tag) => tag.to_dasherized_string(),
+            Mac::Custom(ref custom) => custom.to_dasherized_string(),
+        }
+    }
+
+    /// Serialize this `Mac` as a URI-encoded string into the given buffer
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+        match self {
+            Mac::HmacSha256(ref tag) => tag.encode_into(buf),
+            Mac::HmacSha512(ref tag) => tag.encode_into(buf),
+            Mac::Custom(ref custom) => custom.encode_into(buf),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! HMAC tags
+
+use crate::{
+    algorithm::{HMACSHA256_ALG_ID, HMACSHA512_ALG_ID},
+    error::Error,
+};
+
+/// Size of an HMAC-SHA-256 tag
+pub const HMACSHA256_TAG_SIZE: usize = 32;
+
+/// Size of an HMAC-SHA-512 tag
+pub const HMACSHA512_TAG_SIZE: usize = 64;
+
+/// HMAC-SHA-256 tag
+pub struct HmacSha256Tag(pub [u8; HMACSHA256_TAG_SIZE]);
+
+/// HMAC-SHA-512 tag
+pub struct HmacSha512Tag(pub [u8; HMACSHA512_TAG_SIZE]);
+
+macro_rules! impl_hmac_tag {
+    ($name:ident, $size:expr) => {
+        impl TryFrom<&[u8]> for $name {
+            type Error = Error;
+
+            fn try_from(slice: &[u8]) -> Result<Self, Error> {
+                slice.try_into().map($name).map_err(|_| Error::Length {
+                    actual: slice.len(),
+                    expected: $size,
+                })
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0[..]
+            }
+        }
+
+        impl_constant_time_eq!($name);
+    };
+}
+
+impl_hmac_tag!(HmacSha256Tag, HMACSHA256_TAG_SIZE);
+impl_hmac_tag!(HmacSha512Tag, HMACSHA512_TAG_SIZE);
+
+impl_encodable_mac!(HmacSha256Tag, HMACSHA256_ALG_ID);
+impl_encodable_mac!(HmacSha512Tag, HMACSHA512_ALG_ID);
@@ -0,0 +1,177 @@
+//! minisign and signify public key and signature conversions
+//!
+//! minisign and signify use the same encoding for Ed25519 public keys and
+//! signatures: an algorithm identifier, an 8-byte key ID and the key or
+//! signature, base64-encoded and optionally preceded by an untrusted comment
+//! line. The key ID maps onto the fragment of a CryptoURI, formatted as
+//! upper case hex the same way `minisign` displays it.
+
+use crate::{
+    algorithm::ED25519_ALG_ID, error::Error, public_key::PublicKey, signature::Signature,
+    CryptoUri, CryptoUriKind,
+};
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+use base64ct::{Base64, Encoding};
+
+/// Algorithm identifier for Ed25519 keys and signatures
+const ED25519: &[u8] = b"Ed";
+
+/// Algorithm identifier for minisign signatures of BLAKE2b-512 prehashed
+/// messages (still Ed25519 signatures)
+const ED25519_PREHASHED: &[u8] = b"ED";
+
+/// Size of the algorithm identifier
+const ALG_SIZE: usize = 2;
+
+/// Size of a key ID
+const KEY_ID_SIZE: usize = 8;
+
+/// Prefix of the untrusted comment line
+const UNTRUSTED_COMMENT: &str = "untrusted comment:";
+
+impl PublicKey {
+    /// Decode an Ed25519 `PublicKey` from a minisign or signify public key,
+    /// ignoring its key ID
+    pub fn from_minisign(encoded: &str) -> Result<Self, Error> {
+        parse_public_key(encoded).map(|(key, _)| key)
+    }
+
+    /// Encode this Ed25519 `PublicKey` as a minisign public key file with
+    /// the given key ID
+    pub fn to_minisign(&self, key_id: u64) -> Result<String, Error> {
+        if self.alg_id() != ED25519_ALG_ID {
+            return Err(Error::Algorithm(self.alg_id().to_owned()));
+        }
+
+        let mut blob = ED25519.to_vec();
+        blob.extend_from_slice(&key_id.to_le_bytes());
+        blob.extend_from_slice(self.as_ref());
+
+        Ok(format!(
+            "{} minisign public key {:016X}\n{}\n",
+            UNTRUSTED_COMMENT,
+            key_id,
+            Base64::encode_string(&blob)
+        ))
+    }
+}
+
+impl Signature {
+    /// Decode an Ed25519 `Signature` over the signed file itself from a
+    /// legacy minisign (`minisign -l`) or signify signature, ignoring its key
+    /// ID (and the trusted comment of minisign signatures).
+    ///
+    /// Returns `Error::Algorithm` for prehashed signatures: use
+    /// [`Signature::from_minisign_prehashed`] for those.
+    pub fn from_minisign(encoded: &str) -> Result<Self, Error> {
+        parse_signature(encoded, ED25519).map(|(sig, _)| sig)
+    }
+
+    /// Decode an Ed25519 `Signature` over the BLAKE2b-512 hash of the signed
+    /// file from a prehashed minisign signature (the default since minisign
+    /// 0.11), ignoring its key ID and trusted comment.
+    ///
+    /// Returns `Error::Algorithm` for legacy signatures: use
+    /// [`Signature::from_minisign`] for those.
+    pub fn from_minisign_prehashed(encoded: &str) -> Result<Self, Error> {
+        parse_signature(encoded, ED25519_PREHASHED).map(|(sig, _)| sig)
+    }
+}
+
+impl CryptoUri {
+    /// Decode a minisign or signify public key, using its key ID as the
+    /// fragment
+    pub fn from_minisign_public_key(encoded: &str) -> Result<Self, Error> {
+        let (key, key_id) = parse_public_key(encoded)?;
+
+        Ok(CryptoUri {
+            kind: CryptoUriKind::PublicKey(key),
+            fragment: Some(format_key_id(key_id)),
+        })
+    }
+
+    /// Encode a public key `CryptoUri` as a minisign public key file, using
+    /// its fragment as the key ID
+    pub fn to_minisign_public_key(&self) -> Result<String, Error> {
+        let key_id = self.fragment().ok_or(Error::Parse)?;
+
+        if key_id.len() != KEY_ID_SIZE * 2 {
+            return Err(Error::Parse);
+        }
+
+        self.public_key()
+            .ok_or(Error::Parse)?
+            .to_minisign(u64::from_str_radix(key_id, 16).map_err(|_| Error::Parse)?)
+    }
+
+    /// Decode a legacy minisign or signify signature (see
+    /// [`Signature::from_minisign`]), using its key ID as the fragment.
+    ///
+    /// Returns `Error::Algorithm` for prehashed signatures, as a `CryptoUri`
+    /// can't record that the signature is over a hash of the signed file.
+    pub fn from_minisign_signature(encoded: &str) -> Result<Self, Error> {
+        let (sig, key_id) = parse_signature(encoded, ED25519)?;
+
+        Ok(CryptoUri {
+            kind: CryptoUriKind::Signature(sig),
+            fragment: Some(format_key_id(key_id)),
+        })
+    }
+}
+
+/// Parse a minisign or signify public key into a key and key ID
+fn parse_public_key(encoded: &str) -> Result<(PublicKey, u64), Error> {
+    let (alg, key_id, key) = parse_blob(encoded)?;
+
+    if alg != ED25519 {
+        return Err(Error::Algorithm(String::from_utf8_lossy(&alg).into_owned()));
+    }
+
+    Ok((PublicKey::new(ED25519_ALG_ID, &key)?, key_id))
+}
+
+/// Parse a minisign or signify signature with the given algorithm
+/// identifier into a signature and key ID
+fn parse_signature(encoded: &str, expected_alg: &[u8]) -> Result<(Signature, u64), Error> {
+    let (alg, key_id, sig) = parse_blob(encoded)?;
+
+    if alg != expected_alg {
+        return Err(Error::Algorithm(String::from_utf8_lossy(&alg).into_owned()));
+    }
+
+    Ok((Signature::new(ED25519_ALG_ID, &sig)?, key_id))
+}
+
+/// Parse the first base64 line of a minisign or signify file into its
+/// algorithm identifier, key ID and data
+fn parse_blob(encoded: &str) -> Result<([u8; ALG_SIZE], u64, Vec<u8>), Error> {
+    let mut lines = encoded.lines().map(str::trim).filter(|l| !l.is_empty());
+    let mut line = lines.next().ok_or(Error::Parse)?;
+
+    if line.starts_with(UNTRUSTED_COMMENT) {
+        line = lines.next().ok_or(Error::Parse)?;
+    }
+
+    let blob = Base64::decode_vec(line).map_err(|_| Error::Parse)?;
+
+    if blob.len() < ALG_SIZE + KEY_ID_SIZE {
+        return Err(Error::Parse);
+    }
+
+    let (alg, rest) = blob.split_at(ALG_SIZE);
+    let (key_id, data) = rest.split_at(KEY_ID_SIZE);
+
+    let mut key_id_bytes = [0u8; KEY_ID_SIZE];
+    key_id_bytes.copy_from_slice(key_id);
+
+    Ok((
+        [alg[0], alg[1]],
+        u64::from_le_bytes(key_id_bytes),
+        data.to_vec(),
+    ))
+}
+
+/// Format a key ID the way `minisign` displays it
+fn format_key_id(key_id: u64) -> String {
+    format!("{:016X}", key_id)
+}
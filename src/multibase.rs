@@ -0,0 +1,142 @@
+//! Multibase/multicodec and `did:key` conversions for public keys
+//!
+//! Public keys are prefixed with their multicodec code (as an unsigned
+//! varint) and then encoded using one of the supported [`Multibase`]s.
+//! `did:key` identifiers are always base58btc (e.g. `did:key:z6Mk...`).
+
+use crate::{
+    algorithm::{ED25519_ALG_ID, X25519_ALG_ID},
+    binary::{decode_varint, encode_varint},
+    error::Error,
+    public_key::PublicKey,
+};
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+use base64ct::{Base64Unpadded, Base64UrlUnpadded, Encoding};
+use subtle_encoding::hex;
+
+/// Multicodec codes for public key algorithms
+const PUBLIC_KEY_CODECS: &[(&str, u64)] = &[(ED25519_ALG_ID, 0xed), (X25519_ALG_ID, 0xec)];
+
+/// Prefix of `did:key` identifiers
+const DID_KEY_PREFIX: &str = "did:key:";
+
+/// Supported multibase encodings
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Multibase {
+    /// Lowercase hexadecimal (`f`)
+    Base16,
+
+    /// Bitcoin base58 (`z`)
+    Base58Btc,
+
+    /// Unpadded RFC 4648 base64 (`m`)
+    Base64,
+
+    /// Unpadded RFC 4648 URL-safe base64 (`u`)
+    Base64Url,
+}
+
+impl Multibase {
+    /// Get the multibase for the given prefix character
+    pub fn from_prefix(prefix: char) -> Result<Self, Error> {
+        match prefix {
+            'f' => Ok(Multibase::Base16),
+            'z' => Ok(Multibase::Base58Btc),
+            'm' => Ok(Multibase::Base64),
+            'u' => Ok(Multibase::Base64Url),
+            _ => Err(Error::Parse),
+        }
+    }
+
+    /// Get the prefix character for this multibase
+    pub fn prefix(self) -> char {
+        match self {
+            Multibase::Base16 => 'f',
+            Multibase::Base58Btc => 'z',
+            Multibase::Base64 => 'm',
+            Multibase::Base64Url => 'u',
+        }
+    }
+
+    /// Encode the given data (with a prefix)
+    pub fn encode(self, data: &[u8]) -> String {
+        let mut encoded = String::new();
+        encoded.push(self.prefix());
+        encoded.push_str(&match self {
+            Multibase::Base16 => String::from_utf8(hex::encode(data)).expect("hex is ASCII"),
+            Multibase::Base58Btc => bs58::encode(data).into_string(),
+            Multibase::Base64 => Base64Unpadded::encode_string(data),
+            Multibase::Base64Url => Base64UrlUnpadded::encode_string(data),
+        });
+        encoded
+    }
+
+    /// Decode the given multibase-encoded string
+    pub fn decode(encoded: &str) -> Result<Vec<u8>, Error> {
+        let mut chars = encoded.chars();
+        let base = Self::from_prefix(chars.next().ok_or(Error::Parse)?)?;
+        let data = chars.as_str();
+
+        match base {
+            Multibase::Base16 => hex::decode(data).map_err(|_| Error::Parse),
+            Multibase::Base58Btc => bs58::decode(data).into_vec().map_err(|_| Error::Parse),
+            Multibase::Base64 => Base64Unpadded::decode_vec(data).map_err(|_| Error::Parse),
+            Multibase::Base64Url => Base64UrlUnpadded::decode_vec(data).map_err(|_| Error::Parse),
+        }
+    }
+}
+
+impl PublicKey {
+    /// Decode a `PublicKey` from multicodec-prefixed bytes
+    pub fn from_multicodec(bytes: &[u8]) -> Result<Self, Error> {
+        let (code, key) = decode_varint(bytes)?;
+
+        let (alg_id, _) = PUBLIC_KEY_CODECS
+            .iter()
+            .find(|(_, c)| *c == code)
+            .ok_or_else(|| Error::Algorithm(format!("0x{:x}", code)))?;
+
+        PublicKey::new(alg_id, key)
+    }
+
+    /// Encode this `PublicKey` as multicodec-prefixed bytes
+    pub fn to_multicodec(&self) -> Result<Vec<u8>, Error> {
+        let (_, code) = PUBLIC_KEY_CODECS
+            .iter()
+            .find(|(alg_id, _)| *alg_id == self.alg_id())
+            .ok_or_else(|| Error::Algorithm(self.alg_id().to_owned()))?;
+
+        let mut bytes = Vec::new();
+        encode_varint(&mut bytes, *code);
+        bytes.extend_from_slice(self.as_ref());
+        Ok(bytes)
+    }
+
+    /// Decode a `PublicKey` from a multibase-encoded multicodec key
+    pub fn from_multibase(encoded: &str) -> Result<Self, Error> {
+        Self::from_multicodec(&Multibase::decode(encoded)?)
+    }
+
+    /// Encode this `PublicKey` as a multicodec key with the given multibase
+    pub fn to_multibase(&self, base: Multibase) -> Result<String, Error> {
+        Ok(base.encode(&self.to_multicodec()?))
+    }
+
+    /// Decode a `PublicKey` from a `did:key` identifier. A DID URL fragment
+    /// (e.g. `did:key:z6Mk...#z6Mk...`) is ignored.
+    pub fn from_did_key(did: &str) -> Result<Self, Error> {
+        let id = did.strip_prefix(DID_KEY_PREFIX).ok_or(Error::Parse)?;
+        let id = id.split('#').next().unwrap_or(id);
+
+        if !id.starts_with(Multibase::Base58Btc.prefix()) {
+            return Err(Error::Parse);
+        }
+
+        Self::from_multibase(id)
+    }
+
+    /// Encode this `PublicKey` as a `did:key` identifier
+    pub fn to_did_key(&self) -> Result<String, Error> {
+        Ok(DID_KEY_PREFIX.to_owned() + &self.to_multibase(Multibase::Base58Btc)?)
+    }
+}
@@ -1,7 +1,7 @@
 //! CryptoURI parts
 
-use crate::{encoding::Encoding, error::Error};
-use subtle_encoding::bech32::{self, Bech32};
+use crate::{encoding::Encoding, error::Error, heapless};
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
 use zeroize::Zeroize;
 
 /// Parts of a CryptoURI
@@ -21,29 +21,18 @@ impl Parts {
     pub(crate) fn decode(uri: &str, encoding: &Encoding) -> Result<Self, Error> {
         // Extract the fragment if it exists. Note that fragment is not covered by the
         // bech32 checksum and can be modified (e.g. as a key description)
-        if let Some(delimiter) = encoding.fragment_delimiter {
-            if let Some(pos) = uri.find(delimiter) {
-                let fragment = uri[(pos + 1)..].to_owned();
-                let (prefix, data) = Bech32::new(bech32::DEFAULT_CHARSET, encoding.delimiter)
-                    .decode(&uri[..pos])
-                    .map_err(|_| Error::Parse)?;
-
-                return Ok(Self {
-                    prefix,
-                    data,
-                    fragment: Some(fragment),
-                });
-            }
-        }
-
-        let (prefix, data) = Bech32::new(bech32::DEFAULT_CHARSET, encoding.delimiter)
-            .decode(uri)
-            .map_err(|_| Error::Parse)?;
+        let (encoded, fragment) = heapless::split_fragment(uri, encoding);
+
+        // Decode directly into the output buffer so no intermediate copies of
+        // the (possibly secret) data are left behind
+        let (prefix, payload) = heapless::verify(encoded, encoding.delimiter)?;
+        let mut data = vec![0u8; heapless::payload_len(payload)];
+        heapless::decode_payload(payload, &mut data)?;
 
         Ok(Self {
-            prefix,
+            prefix: prefix.to_ascii_lowercase(),
             data,
-            fragment: None,
+            fragment: fragment.map(ToOwned::to_owned),
         })
     }
 }
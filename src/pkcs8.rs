@@ -0,0 +1,155 @@
+//! PKCS#8 (RFC 5208) and SPKI (RFC 5280) conversions for keys
+//!
+//! Supports the asymmetric algorithms in the [`algorithm`] registry which
+//! have an ASN.1 OID, with private keys encoded as described in RFC 8410
+//! (i.e. an OCTET STRING containing the raw key).
+
+use crate::{
+    algorithm::{self, Info},
+    error::Error,
+    public_key::PublicKey,
+    secret_key::SecretKey,
+};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+use pkcs8::{
+    der::{
+        asn1::{BitStringRef, OctetStringRef},
+        pem::LineEnding,
+        Decode, Document, Encode, SecretDocument,
+    },
+    spki::{AlgorithmIdentifierRef, SubjectPublicKeyInfoRef},
+    ObjectIdentifier, PrivateKeyInfo,
+};
+use zeroize::Zeroizing;
+
+/// PEM label for SPKI public keys
+const PUBLIC_KEY_PEM_LABEL: &str = "PUBLIC KEY";
+
+/// PEM label for PKCS#8 private keys
+const PRIVATE_KEY_PEM_LABEL: &str = "PRIVATE KEY";
+
+impl SecretKey {
+    /// Decode a PKCS#8 DER-encoded private key
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, Error> {
+        let private_key_info = PrivateKeyInfo::from_der(der).map_err(|_| Error::Parse)?;
+        let info = asymmetric_algorithm(private_key_info.algorithm.oid)?;
+
+        let key =
+            OctetStringRef::from_der(private_key_info.private_key).map_err(|_| Error::Parse)?;
+        SecretKey::new(info.id, key.as_bytes())
+    }
+
+    /// Decode a PKCS#8 PEM-encoded private key
+    pub fn from_pkcs8_pem(pem: &str) -> Result<Self, Error> {
+        let (label, der) = SecretDocument::from_pem(pem).map_err(|_| Error::Parse)?;
+
+        if label != PRIVATE_KEY_PEM_LABEL {
+            return Err(Error::Parse);
+        }
+
+        Self::from_pkcs8_der(der.as_bytes())
+    }
+
+    /// Encode this `SecretKey` as a PKCS#8 DER-encoded private key
+    pub fn to_pkcs8_der(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        Ok(self.to_pkcs8_document()?.to_bytes())
+    }
+
+    /// Encode this `SecretKey` as a PKCS#8 PEM-encoded private key
+    pub fn to_pkcs8_pem(&self) -> Result<Zeroizing<String>, Error> {
+        self.to_pkcs8_document()?
+            .to_pem(PRIVATE_KEY_PEM_LABEL, LineEnding::LF)
+            .map_err(|_| Error::Parse)
+    }
+
+    /// Encode this `SecretKey` as a PKCS#8 document
+    fn to_pkcs8_document(&self) -> Result<SecretDocument, Error> {
+        let info = algorithm::lookup(self.alg_id())
+            .filter(|info| info.public_key_size.is_some() && self.pipeline().is_none())
+            .ok_or_else(|| Error::Algorithm(self.alg_id().to_owned()))?;
+
+        let key = Zeroizing::new(
+            OctetStringRef::new(self.as_bytes())
+                .and_then(|key| key.to_der())
+                .map_err(|_| Error::Parse)?,
+        );
+
+        let private_key_info = PrivateKeyInfo::new(algorithm_identifier(info)?, &key);
+        SecretDocument::try_from(private_key_info).map_err(|_| Error::Parse)
+    }
+}
+
+impl PublicKey {
+    /// Decode a DER-encoded SPKI public key
+    pub fn from_public_key_der(der: &[u8]) -> Result<Self, Error> {
+        let spki = SubjectPublicKeyInfoRef::from_der(der).map_err(|_| Error::Parse)?;
+        let info = asymmetric_algorithm(spki.algorithm.oid)?;
+
+        let key = spki.subject_public_key.as_bytes().ok_or(Error::Parse)?;
+        PublicKey::new(info.id, key)
+    }
+
+    /// Decode a PEM-encoded SPKI public key
+    pub fn from_public_key_pem(pem: &str) -> Result<Self, Error> {
+        let (label, der) = Document::from_pem(pem).map_err(|_| Error::Parse)?;
+
+        if label != PUBLIC_KEY_PEM_LABEL {
+            return Err(Error::Parse);
+        }
+
+        Self::from_public_key_der(der.as_bytes())
+    }
+
+    /// Encode this `PublicKey` as a DER-encoded SPKI public key
+    pub fn to_public_key_der(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.to_public_key_document()?.into_vec())
+    }
+
+    /// Encode this `PublicKey` as a PEM-encoded SPKI public key
+    pub fn to_public_key_pem(&self) -> Result<String, Error> {
+        self.to_public_key_document()?
+            .to_pem(PUBLIC_KEY_PEM_LABEL, LineEnding::LF)
+            .map_err(|_| Error::Parse)
+    }
+
+    /// Encode this `PublicKey` as an SPKI document
+    fn to_public_key_document(&self) -> Result<Document, Error> {
+        let info = algorithm::lookup(self.alg_id())
+            .ok_or_else(|| Error::Algorithm(self.alg_id().to_owned()))?;
+
+        let spki = SubjectPublicKeyInfoRef {
+            algorithm: algorithm_identifier(info)?,
+            subject_public_key: BitStringRef::from_bytes(self.as_ref())
+                .map_err(|_| Error::Parse)?,
+        };
+
+        Document::encode_msg(&spki).map_err(|_| Error::Parse)
+    }
+}
+
+/// Find the asymmetric algorithm in the registry with the given OID
+fn asymmetric_algorithm(oid: ObjectIdentifier) -> Result<&'static Info, Error> {
+    let oid = oid.to_string();
+
+    algorithm::lookup_oid(&oid)
+        .filter(|info| info.public_key_size.is_some())
+        .ok_or(Error::Algorithm(oid))
+}
+
+/// Get the ASN.1 algorithm identifier for the given algorithm
+fn algorithm_identifier(info: &Info) -> Result<AlgorithmIdentifierRef<'static>, Error> {
+    let oid = info
+        .oid
+        .ok_or_else(|| Error::Algorithm(info.id.to_owned()))?
+        .parse()
+        .map_err(|_| Error::Algorithm(info.id.to_owned()))?;
+
+    Ok(AlgorithmIdentifierRef {
+        oid,
+        parameters: None,
+    })
+}
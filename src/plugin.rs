@@ -0,0 +1,200 @@
+//! User-registered algorithm plugins.
+//!
+//! Plugins allow CryptoURIs which use algorithms not built into this crate
+//! to be parsed. Once registered, objects using a plugin's algorithm parse
+//! as a [`Custom`] value (e.g. [`PublicKey::Custom`][crate::PublicKey::Custom])
+//! which can be serialized again via [`Encodable`].
+//!
+//! The plugin registry requires the `std` feature. Without it no plugins
+//! can be registered, so [`lookup`] always returns `None`.
+//!
+//! ```
+//! use cryptouri::{algorithm::Length, plugin::{self, Plugin}, CryptoUri, Encodable, Kind};
+//!
+//! struct Example;
+//!
+//! impl Plugin for Example {
+//!     fn alg_id(&self) -> &'static str {
+//!         "example"
+//!     }
+//!
+//!     fn length(&self, kind: Kind) -> Option<Length> {
+//!         match kind {
+//!             Kind::PublicKey => Some(Length::Fixed(4)),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//!
+//! # #[cfg(feature = "std")] {
+//! plugin::register(&Example).unwrap();
+//!
+//! let uri = "crypto:pub:key:example:qypqxpqmvw3cd";
+//! let key = CryptoUri::parse_uri(uri).unwrap();
+//! let custom = key.public_key().unwrap().custom().unwrap();
+//! assert_eq!(custom.alg_id(), "example");
+//! assert_eq!(custom.as_ref(), &[1, 2, 3, 4]);
+//! assert_eq!(key.to_uri_string(), uri);
+//! # }
+//! ```
+
+use crate::{
+    algorithm::Length,
+    encoding::{self, Encodable, Encoding, DASHERIZED_ENCODING, URI_ENCODING},
+    error::Error,
+    kind::Kind,
+};
+use alloc::{borrow::ToOwned, boxed::Box, string::String};
+use core::mem;
+use zeroize::Zeroize;
+
+#[cfg(feature = "std")]
+use {crate::algorithm, alloc::vec::Vec, std::sync::RwLock};
+
+/// Registered plugins
+#[cfg(feature = "std")]
+static PLUGINS: RwLock<Vec<&'static dyn Plugin>> = RwLock::new(Vec::new());
+
+/// Algorithm plugins: describe algorithms which aren't built into this crate
+pub trait Plugin: Sync {
+    /// Algorithm identifier as used in CryptoURIs
+    fn alg_id(&self) -> &'static str;
+
+    /// Length requirement for the data of CryptoURIs of the given kind which
+    /// use this algorithm, or `None` if the algorithm doesn't support it
+    fn length(&self, kind: Kind) -> Option<Length>;
+
+    /// Validate the data of a CryptoURI of the given kind before a
+    /// [`Custom`] value is constructed from it (e.g. check a public key is a
+    /// valid curve point). Its length has already been checked.
+    fn validate(&self, kind: Kind, bytes: &[u8]) -> Result<(), Error> {
+        let _ = (kind, bytes);
+        Ok(())
+    }
+}
+
+/// Register a plugin (typically at startup).
+///
+/// Returns `Error::Algorithm` if the plugin's algorithm identifier isn't
+/// well-formed (see [`algorithm::is_valid_id`]), or is already in use by a
+/// built-in algorithm or another plugin.
+#[cfg(feature = "std")]
+pub fn register(plugin: &'static dyn Plugin) -> Result<(), Error> {
+    let alg_id = plugin.alg_id();
+    let mut plugins = PLUGINS.write().unwrap_or_else(|e| e.into_inner());
+
+    if !algorithm::is_valid_id(alg_id)
+        || algorithm::lookup(alg_id).is_some()
+        || plugins.iter().any(|p| p.alg_id() == alg_id)
+    {
+        return Err(Error::Algorithm(alg_id.to_owned()));
+    }
+
+    plugins.push(plugin);
+    Ok(())
+}
+
+/// Look up a registered plugin by its algorithm identifier
+#[cfg(feature = "std")]
+pub fn lookup(alg_id: &str) -> Option<&'static dyn Plugin> {
+    PLUGINS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .find(|plugin| plugin.alg_id() == alg_id)
+        .copied()
+}
+
+/// Look up a registered plugin by its algorithm identifier (always `None`,
+/// as the plugin registry requires the `std` feature)
+#[cfg(not(feature = "std"))]
+pub fn lookup(alg_id: &str) -> Option<&'static dyn Plugin> {
+    let _ = alg_id;
+    None
+}
+
+/// Object whose algorithm is provided by a registered [`Plugin`]
+#[derive(Clone)]
+pub struct Custom {
+    /// Kind of CryptoURI
+    kind: Kind,
+
+    /// Algorithm identifier
+    alg_id: &'static str,
+
+    /// Data (zeroized on drop, as it may be a secret key)
+    bytes: Box<[u8]>,
+}
+
+impl Custom {
+    /// Create a new `Custom` object of the given kind using a registered
+    /// plugin for the given algorithm
+    pub fn new(kind: Kind, alg_id: &str, bytes: &[u8]) -> Result<Self, Error> {
+        let plugin = lookup(alg_id).ok_or_else(|| Error::Algorithm(alg_id.to_owned()))?;
+
+        plugin
+            .length(kind)
+            .ok_or_else(|| Error::Algorithm(alg_id.to_owned()))?
+            .check(bytes.len())?;
+
+        plugin.validate(kind, bytes)?;
+
+        Ok(Custom {
+            kind,
+            alg_id: plugin.alg_id(),
+            bytes: bytes.into(),
+        })
+    }
+
+    /// Get the kind of CryptoURI this object is
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Get the algorithm identifier for this object
+    pub fn alg_id(&self) -> &'static str {
+        self.alg_id
+    }
+
+    /// Encode this object using the given encoding
+    fn encode(&self, encoding: &Encoding) -> String {
+        // May be a secret key
+        mem::take(&mut *encoding::encode_zeroizing(
+            encoding,
+            self.kind,
+            self.alg_id,
+            &self.bytes[..],
+        ))
+    }
+}
+
+impl AsRef<[u8]> for Custom {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Drop for Custom {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl Encodable for Custom {
+    fn to_uri_string(&self) -> String {
+        self.encode(URI_ENCODING)
+    }
+
+    fn to_dasherized_string(&self) -> String {
+        self.encode(DASHERIZED_ENCODING)
+    }
+
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+        encoding::encode_into(
+            buf,
+            URI_ENCODING.scheme(self.kind),
+            self.alg_id,
+            &self.bytes,
+        )
+    }
+}
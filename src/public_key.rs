@@ -1,32 +1,79 @@
 //! Public key types
 
-use crate::{algorithm::ED25519_ALG_ID, encoding::Encodable, error::Error};
-use std::convert::TryInto;
+#[cfg(all(feature = "alloc", feature = "sha2"))]
+use crate::hash::{Hash, Sha256Hash};
+#[cfg(feature = "alloc")]
+use {
+    crate::{
+        algorithm::{self, ED25519_ALG_ID, X25519_ALG_ID},
+        encoding::Encodable,
+        error::Error,
+        kind::Kind,
+        plugin::Custom,
+        CryptoUri,
+    },
+    alloc::{borrow::ToOwned, string::String},
+    core::convert::TryInto,
+};
 
 /// Ed25519 elliptic curve digital signature algorithm (RFC 8032)
 mod ed25519;
 
-pub use self::ed25519::Ed25519PublicKey;
+/// X25519 elliptic curve Diffie-Hellman (RFC 7748)
+mod x25519;
+
+pub use self::{ed25519::Ed25519PublicKey, x25519::X25519PublicKey};
 
 /// Public key algorithms
+#[cfg(feature = "alloc")]
 pub enum PublicKey {
     /// Ed25519 (RFC 8032) public key
     Ed25519(Ed25519PublicKey),
+
+    /// X25519 (RFC 7748) public key
+    X25519(X25519PublicKey),
+
+    /// Public key using an algorithm provided by a plugin
+    Custom(Custom),
 }
 
+#[cfg(feature = "alloc")]
 impl PublicKey {
+    /// Parse a `PublicKey` from a `crypto:pub:key:` URI (ignoring its fragment), returning
+    /// `Error::Kind` if the URI is of a different kind
+    pub fn from_uri_str(uri: &str) -> Result<Self, Error> {
+        CryptoUri::parse_uri_of_kind(uri, Kind::PublicKey)?.try_into()
+    }
+
     /// Create a new `PublicKey` for the given algorithm
     pub fn new(alg: &str, bytes: &[u8]) -> Result<Self, Error> {
+        if algorithm::lookup(alg).is_none() {
+            return Ok(PublicKey::Custom(Custom::new(Kind::PublicKey, alg, bytes)?));
+        }
+
+        algorithm::validate(alg, Kind::PublicKey, bytes.len())?;
+
         match alg {
             ED25519_ALG_ID => Ok(PublicKey::Ed25519(bytes.try_into()?)),
+            X25519_ALG_ID => Ok(PublicKey::X25519(bytes.try_into()?)),
             _ => Err(Error::Algorithm(alg.to_owned())),
         }
     }
 
+    /// Get the algorithm identifier for this `PublicKey`
+    pub fn alg_id(&self) -> &'static str {
+        match self {
+            PublicKey::Ed25519(_) => ED25519_ALG_ID,
+            PublicKey::X25519(_) => X25519_ALG_ID,
+            PublicKey::Custom(ref custom) => custom.alg_id(),
+        }
+    }
+
     /// Return an `Ed25519PublicKey` if the underlying public key is Ed25519
     pub fn ed25519_key(&self) -> Option<&Ed25519PublicKey> {
         match self {
             PublicKey::Ed25519(ref key) => Some(key),
+            _ => None,
         }
     }
 
@@ -34,13 +81,74 @@ impl PublicKey {
     pub fn is_ed25519_key(&self) -> bool {
         self.ed25519_key().is_some()
     }
+
+    /// Return an `X25519PublicKey` if the underlying public key is X25519
+    pub fn x25519_key(&self) -> Option<&X25519PublicKey> {
+        match self {
+            PublicKey::X25519(ref key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Is this `PublicKey` an X25519 public key?
+    pub fn is_x25519_key(&self) -> bool {
+        self.x25519_key().is_some()
+    }
+
+    /// Return a `Custom` public key if the underlying algorithm is provided by a
+    /// plugin
+    pub fn custom(&self) -> Option<&Custom> {
+        match self {
+            PublicKey::Custom(ref custom) => Some(custom),
+            _ => None,
+        }
+    }
+
+    /// Is this `PublicKey` provided by a plugin?
+    pub fn is_custom(&self) -> bool {
+        self.custom().is_some()
+    }
+
+    /// Compute the fingerprint of this `PublicKey`: the SHA-256 digest of its
+    /// CryptoURI (so it also commits to the key's algorithm)
+    #[cfg(feature = "sha2")]
+    pub fn fingerprint(&self) -> Hash {
+        Hash::Sha256(Sha256Hash::digest(self.to_uri_string().as_bytes()))
+    }
+}
+
+impl_try_from_crypto_uri!(
+    Ed25519PublicKey,
+    PublicKey,
+    PublicKey::Ed25519,
+    ED25519_ALG_ID
+);
+impl_try_from_crypto_uri!(X25519PublicKey, PublicKey, PublicKey::X25519, X25519_ALG_ID);
+
+#[cfg(feature = "alloc")]
+impl_try_from_crypto_uri_kind!(PublicKey);
+#[cfg(feature = "alloc")]
+impl_hex_base64!(PublicKey);
+
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            PublicKey::Ed25519(ref key) => key.as_ref(),
+            PublicKey::X25519(ref key) => key.as_ref(),
+            PublicKey::Custom(ref custom) => custom.as_ref(),
+        }
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl Encodable for PublicKey {
     /// Serialize this `PublicKey` as a URI-encoded `String`
     fn to_uri_string(&self) -> String {
         match self {
             PublicKey::Ed25519(ref key) => key.to_uri_string(),
+            PublicKey::X25519(ref key) => key.to_uri_string(),
+            PublicKey::Custom(ref custom) => custom.to_uri_string(),
         }
     }
 
@@ -48,6 +156,17 @@ impl Encodable for PublicKey {
     fn to_dasherized_string(&self) -> String {
         match self {
             PublicKey::Ed25519(ref key) => key.to_dasherized_string(),
+            PublicKey::X25519(ref key) => key.to_dasherized_string(),
+            PublicKey::Custom(ref custom) => custom.to_dasherized_string(),
+        }
+    }
+
+    /// Serialize this `PublicKey` as a URI-encoded string into the given buffer
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+        match self {
+            PublicKey::Ed25519(ref key) => key.encode_into(buf),
+            PublicKey::X25519(ref key) => key.encode_into(buf),
+            PublicKey::Custom(ref custom) => custom.encode_into(buf),
         }
     }
 }
@@ -1,7 +1,10 @@
 //! Ed25519 public keys
 
 use crate::{algorithm::ED25519_ALG_ID, error::Error};
-use std::convert::{TryFrom, TryInto};
+use core::convert::{TryFrom, TryInto};
+
+#[cfg(feature = "ed25519-dalek")]
+use crate::signature::Ed25519Signature;
 
 /// Size of an Ed25519 public key
 pub const ED25519_PUBKEY_SIZE: usize = 32;
@@ -9,6 +12,18 @@ pub const ED25519_PUBKEY_SIZE: usize = 32;
 /// Ed25519 public key (i.e. compressed Edwards-y coordinate)
 pub struct Ed25519PublicKey(pub [u8; ED25519_PUBKEY_SIZE]);
 
+impl Ed25519PublicKey {
+    /// Verify that the given signature over the given message was made by
+    /// the secret key for this public key (rejecting weak keys and
+    /// malleable signatures)
+    #[cfg(feature = "ed25519-dalek")]
+    pub fn verify(&self, msg: &[u8], sig: &Ed25519Signature) -> Result<(), Error> {
+        ed25519_dalek::VerifyingKey::from_bytes(&self.0)
+            .and_then(|key| key.verify_strict(msg, &ed25519_dalek::Signature::from_bytes(&sig.0)))
+            .map_err(|_| Error::Verification)
+    }
+}
+
 impl TryFrom<&[u8]> for Ed25519PublicKey {
     type Error = Error;
 
@@ -0,0 +1,32 @@
+//! X25519 public keys
+
+use crate::{algorithm::X25519_ALG_ID, error::Error};
+use core::convert::{TryFrom, TryInto};
+
+/// Size of an X25519 public key
+pub const X25519_PUBKEY_SIZE: usize = 32;
+
+/// X25519 public key (i.e. Montgomery-u coordinate)
+pub struct X25519PublicKey(pub [u8; X25519_PUBKEY_SIZE]);
+
+impl TryFrom<&[u8]> for X25519PublicKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Error> {
+        slice
+            .try_into()
+            .map(X25519PublicKey)
+            .map_err(|_| Error::Length {
+                actual: slice.len(),
+                expected: X25519_PUBKEY_SIZE,
+            })
+    }
+}
+
+impl AsRef<[u8]> for X25519PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0[..]
+    }
+}
+
+impl_encodable_public_key!(X25519PublicKey, X25519_ALG_ID);
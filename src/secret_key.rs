@@ -1,32 +1,199 @@
 //! Secret Key types
+//!
+//! With the `mlock` feature, AES-GCM, ChaCha20Poly1305, Ed25519 and
+//! HKDF-SHA-256 keys are locked into RAM where possible (see their
+//! `is_locked` methods), on a page of their own between two guard pages.
+
+/// Implement support for combining a key derivation function secret key with
+/// a pipeline of other algorithms (i.e. the types of keys to derive)
+macro_rules! impl_kdf_key {
+    ($name:ident, $alg:expr, $alg_id:expr) => {
+        impl $name {
+            /// Create a new key which derives keys for the given algorithm
+            pub fn new(bytes: &[u8], derived_alg: Algorithm) -> Result<Self, Error> {
+                Self::with_pipeline(bytes, Pipeline::new($alg, &[derived_alg])?)
+            }
+
+            /// Create a new key which derives keys for each stage of the
+            /// given pipeline in turn
+            pub fn with_pipeline(bytes: &[u8], pipeline: Pipeline) -> Result<Self, Error> {
+                pipeline.validate($alg)?;
+                let mut key = Self::try_from(bytes)?;
+                key.pipeline = Some(pipeline);
+                Ok(key)
+            }
+
+            /// Get the algorithm for the key to derive (if specified)
+            pub fn derived_alg(&self) -> Option<Algorithm> {
+                self.pipeline.as_ref().map(|pipeline| pipeline.stages()[0])
+            }
+
+            /// Get the pipeline of algorithms to derive keys for (if specified)
+            pub fn pipeline(&self) -> Option<&Pipeline> {
+                self.pipeline.as_ref()
+            }
+
+            /// Encode this key using the given encoding
+            fn encode(&self, encoding: &Encoding) -> String {
+                let mut alg_id = $alg_id.to_owned();
+
+                if let Some(pipeline) = &self.pipeline {
+                    for stage in pipeline.stages() {
+                        alg_id.push(encoding.combine);
+                        alg_id.push_str(&stage.to_string());
+                    }
+                }
+
+                core::mem::take(&mut *$crate::encoding::encode_zeroizing(
+                    encoding,
+                    $crate::kind::Kind::SecretKey,
+                    &alg_id,
+                    &self.as_ref()[..],
+                ))
+            }
+        }
+
+        impl subtle::ConstantTimeEq for $name {
+            fn ct_eq(&self, other: &Self) -> subtle::Choice {
+                subtle::Choice::from(u8::from(self.pipeline == other.pipeline))
+                    & subtle::ConstantTimeEq::ct_eq(&self.as_ref()[..], &other.as_ref()[..])
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                subtle::ConstantTimeEq::ct_eq(self, other).into()
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl Encodable for $name {
+            #[inline]
+            fn to_uri_string(&self) -> String {
+                self.encode(URI_ENCODING)
+            }
+
+            #[inline]
+            fn to_dasherized_string(&self) -> String {
+                self.encode(DASHERIZED_ENCODING)
+            }
+
+            fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+                let mut encoder = Encoder::new(buf);
+                encoder.push(URI_ENCODING.secret_key_scheme);
+                encoder.push($alg_id);
+
+                if let Some(pipeline) = &self.pipeline {
+                    for stage in pipeline.stages() {
+                        encoder.push_char(URI_ENCODING.combine);
+                        encoder.push(stage.as_str());
+                    }
+                }
+
+                encoder.finish(URI_ENCODING.delimiter, &self.as_ref()[..])
+            }
+        }
+    };
+}
+
+/// Define a fixed-size key derivation function secret key
+macro_rules! fixed_size_kdf_key {
+    ($name:ident, $key_size:expr, $alg:expr, $alg_id:expr, $desc:expr) => {
+        #[doc = $desc]
+        #[derive(Clone)]
+        pub struct $name {
+            /// Input key material
+            ikm: SecretBytes<$key_size>,
+
+            /// Key types to derive (if specified)
+            pipeline: Option<Pipeline>,
+        }
+
+        impl $name {
+            /// Is this key locked into memory (i.e. it can't be swapped to disk)?
+            #[cfg(feature = "mlock")]
+            pub fn is_locked(&self) -> bool {
+                self.ikm.is_locked()
+            }
+        }
+
+        impl AsRef<[u8; $key_size]> for $name {
+            fn as_ref(&self) -> &[u8; $key_size] {
+                self.ikm.as_ref()
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = Error;
+
+            fn try_from(slice: &[u8]) -> Result<Self, Error> {
+                <&[u8; $key_size]>::try_from(slice)
+                    .map(|bytes| $name {
+                        ikm: SecretBytes::new(bytes),
+                        pipeline: None,
+                    })
+                    .map_err(|_| Error::Length {
+                        actual: slice.len(),
+                        expected: $key_size,
+                    })
+            }
+        }
+
+        impl ZeroizeOnDrop for $name {}
+
+        impl_kdf_key!($name, $alg, $alg_id);
+    };
+}
 
 mod aesgcm;
+mod argon2;
 mod chacha20poly1305;
 mod ed25519;
 mod hkdf;
+mod hmac;
+mod kbkdf;
+mod pipeline;
+mod storage;
+mod x25519;
 
 pub use self::{
     aesgcm::{Aes128GcmKey, Aes256GcmKey},
+    argon2::Argon2idKey,
     chacha20poly1305::ChaCha20Poly1305Key,
     ed25519::Ed25519SecretKey,
-    hkdf::HkdfSha256Key,
+    hkdf::{HkdfSha256Key, HkdfSha384Key, HkdfSha512Key},
+    hmac::{HmacSha256Key, HmacSha512Key},
+    kbkdf::KbkdfHmacSha256Key,
+    pipeline::Pipeline,
+    x25519::X25519SecretKey,
 };
 
 use crate::{
     algorithm::{
-        AES128GCM_ALG_ID, AES256GCM_ALG_ID, CHACHA20POLY1305_ALG_ID, ED25519_ALG_ID,
-        HKDFSHA256_ALG_ID,
+        self, Category, Info, AES128GCM_ALG_ID, AES256GCM_ALG_ID, ARGON2ID_ALG_ID,
+        CHACHA20POLY1305_ALG_ID, ED25519_ALG_ID, HKDFSHA256_ALG_ID, HKDFSHA384_ALG_ID,
+        HKDFSHA512_ALG_ID, HMACSHA256_ALG_ID, HMACSHA512_ALG_ID, KBKDFHMACSHA256_ALG_ID,
+        X25519_ALG_ID,
     },
-    encoding::Encodable,
+    encoding::{self, Encodable},
     error::Error,
+    kind::Kind,
+    plugin::Custom,
+    CryptoUri,
 };
-use std::{
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::{
     fmt::{self, Display},
     str::FromStr,
 };
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroizing;
+
+#[cfg(any(feature = "ed25519-dalek", feature = "x25519-dalek"))]
+use crate::public_key::PublicKey;
 
 /// Secret key algorithms
-// TODO(tarcieri): factor these apart by algorithm category (AEADs, KDFs, signature etc)
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum Algorithm {
     /// AES-128 in Galois/Counter Mode
@@ -35,6 +202,9 @@ pub enum Algorithm {
     /// AES-256 in Galois/Counter Mode
     Aes256Gcm,
 
+    /// Argon2id password hashing function (RFC 9106)
+    Argon2id,
+
     /// ChaCha20Poly1305 AEAD
     ChaCha20Poly1305,
 
@@ -43,17 +213,59 @@ pub enum Algorithm {
 
     /// HKDF (RFC 5869) instantiated with HMAC-SHA-256
     HkdfSha256,
+
+    /// HKDF (RFC 5869) instantiated with HMAC-SHA-384
+    HkdfSha384,
+
+    /// HKDF (RFC 5869) instantiated with HMAC-SHA-512
+    HkdfSha512,
+
+    /// HMAC (RFC 2104) instantiated with SHA-256
+    HmacSha256,
+
+    /// HMAC (RFC 2104) instantiated with SHA-512
+    HmacSha512,
+
+    /// NIST SP 800-108 KDF in counter mode instantiated with HMAC-SHA-256
+    KbkdfHmacSha256,
+
+    /// X25519 elliptic curve Diffie-Hellman
+    X25519,
 }
 
-impl Display for Algorithm {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
+impl Algorithm {
+    /// Get the CryptoURI identifier for this algorithm
+    pub fn as_str(self) -> &'static str {
+        match self {
             Algorithm::Aes128Gcm => AES128GCM_ALG_ID,
             Algorithm::Aes256Gcm => AES256GCM_ALG_ID,
+            Algorithm::Argon2id => ARGON2ID_ALG_ID,
             Algorithm::ChaCha20Poly1305 => CHACHA20POLY1305_ALG_ID,
             Algorithm::Ed25519 => ED25519_ALG_ID,
             Algorithm::HkdfSha256 => HKDFSHA256_ALG_ID,
-        })
+            Algorithm::HkdfSha384 => HKDFSHA384_ALG_ID,
+            Algorithm::HkdfSha512 => HKDFSHA512_ALG_ID,
+            Algorithm::HmacSha256 => HMACSHA256_ALG_ID,
+            Algorithm::HmacSha512 => HMACSHA512_ALG_ID,
+            Algorithm::KbkdfHmacSha256 => KBKDFHMACSHA256_ALG_ID,
+            Algorithm::X25519 => X25519_ALG_ID,
+        }
+    }
+
+    /// Get the registry entry describing this algorithm
+    pub fn info(self) -> &'static Info {
+        algorithm::lookup(self.as_str()).expect("secret key algorithm missing from registry")
+    }
+
+    /// Get the category of this algorithm (e.g. AEAD, KDF, signature)
+    pub fn category(self) -> Category {
+        self.info().category
+    }
+}
+
+impl Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
     }
 }
 
@@ -64,14 +276,44 @@ impl FromStr for Algorithm {
         Ok(match s {
             AES128GCM_ALG_ID => Algorithm::Aes128Gcm,
             AES256GCM_ALG_ID => Algorithm::Aes256Gcm,
+            ARGON2ID_ALG_ID => Algorithm::Argon2id,
             CHACHA20POLY1305_ALG_ID => Algorithm::ChaCha20Poly1305,
             ED25519_ALG_ID => Algorithm::Ed25519,
             HKDFSHA256_ALG_ID => Algorithm::HkdfSha256,
+            HKDFSHA384_ALG_ID => Algorithm::HkdfSha384,
+            HKDFSHA512_ALG_ID => Algorithm::HkdfSha512,
+            HMACSHA256_ALG_ID => Algorithm::HmacSha256,
+            HMACSHA512_ALG_ID => Algorithm::HmacSha512,
+            KBKDFHMACSHA256_ALG_ID => Algorithm::KbkdfHmacSha256,
+            X25519_ALG_ID => Algorithm::X25519,
             _ => return Err(Error::Algorithm(s.to_owned())),
         })
     }
 }
 
+/// Constructor for a KDF key which derives keys for the given `Pipeline`
+type KdfKeyConstructor = fn(&[u8], Pipeline) -> Result<SecretKey, Error>;
+
+/// Registry of key derivation functions which can be combined with other
+/// algorithms (i.e. appear first in a combination like `hkdfsha256+aes256gcm`)
+const KDF_ALGORITHMS: &[(Algorithm, KdfKeyConstructor)] = &[
+    (Algorithm::Argon2id, |bytes, pipeline| {
+        Argon2idKey::with_pipeline(bytes, pipeline).map(SecretKey::Argon2id)
+    }),
+    (Algorithm::HkdfSha256, |bytes, pipeline| {
+        HkdfSha256Key::with_pipeline(bytes, pipeline).map(SecretKey::HkdfSha256)
+    }),
+    (Algorithm::HkdfSha384, |bytes, pipeline| {
+        HkdfSha384Key::with_pipeline(bytes, pipeline).map(SecretKey::HkdfSha384)
+    }),
+    (Algorithm::HkdfSha512, |bytes, pipeline| {
+        HkdfSha512Key::with_pipeline(bytes, pipeline).map(SecretKey::HkdfSha512)
+    }),
+    (Algorithm::KbkdfHmacSha256, |bytes, pipeline| {
+        KbkdfHmacSha256Key::with_pipeline(bytes, pipeline).map(SecretKey::KbkdfHmacSha256)
+    }),
+];
+
 /// Secret key types
 pub enum SecretKey {
     /// AES-128 in Galois/Counter Mode
@@ -80,6 +322,9 @@ pub enum SecretKey {
     /// AES-256 in Galois/Counter Mode
     Aes256Gcm(Aes256GcmKey),
 
+    /// Argon2id password
+    Argon2id(Argon2idKey),
+
     /// ChaCha20Poly1305 AEAD
     ChaCha20Poly1305(ChaCha20Poly1305Key),
 
@@ -89,35 +334,215 @@ pub enum SecretKey {
     /// HMAC-based Extract-and-Expand Key Derivation Function (HKDF)
     /// instantiated with HMAC-SHA-256
     HkdfSha256(HkdfSha256Key),
+
+    /// HKDF instantiated with HMAC-SHA-384
+    HkdfSha384(HkdfSha384Key),
+
+    /// HKDF instantiated with HMAC-SHA-512
+    HkdfSha512(HkdfSha512Key),
+
+    /// HMAC-SHA-256 (RFC 2104) key
+    HmacSha256(HmacSha256Key),
+
+    /// HMAC-SHA-512 (RFC 2104) key
+    HmacSha512(HmacSha512Key),
+
+    /// NIST SP 800-108 counter mode KDF instantiated with HMAC-SHA-256
+    KbkdfHmacSha256(KbkdfHmacSha256Key),
+
+    /// X25519 private scalar
+    X25519(X25519SecretKey),
+
+    /// Secret key using an algorithm provided by a plugin
+    Custom(Custom),
 }
 
 impl SecretKey {
+    /// Parse a `SecretKey` from a `crypto:sec:key:` URI (ignoring its
+    /// fragment), returning `Error::Kind` if the URI is of a different kind.
+    ///
+    /// Errors never include any part of the input, in case it's a secret
+    /// key with a typo in it.
+    pub fn from_uri_str(uri: &str) -> Result<Self, Error> {
+        CryptoUri::parse_uri_of_kind(uri, Kind::SecretKey)
+            .and_then(TryInto::try_into)
+            .map_err(|e| match e {
+                Error::Algorithm(_) | Error::Scheme(_) => Error::Parse,
+                other => other,
+            })
+    }
+
     /// Create a new `SecretKey` for the given algorithm
     pub fn new(alg: &str, slice: &[u8]) -> Result<Self, Error> {
+        if algorithm::lookup(alg).is_none() {
+            return Ok(SecretKey::Custom(Custom::new(Kind::SecretKey, alg, slice)?));
+        }
+
+        algorithm::validate(alg, Kind::SecretKey, slice.len())?;
+
         Ok(match alg {
             AES128GCM_ALG_ID => SecretKey::Aes128Gcm(slice.try_into()?),
             AES256GCM_ALG_ID => SecretKey::Aes256Gcm(slice.try_into()?),
+            ARGON2ID_ALG_ID => SecretKey::Argon2id(slice.try_into()?),
             CHACHA20POLY1305_ALG_ID => SecretKey::ChaCha20Poly1305(slice.try_into()?),
             ED25519_ALG_ID => SecretKey::Ed25519(slice.try_into()?),
             HKDFSHA256_ALG_ID => SecretKey::HkdfSha256(slice.try_into()?),
+            HKDFSHA384_ALG_ID => SecretKey::HkdfSha384(slice.try_into()?),
+            HKDFSHA512_ALG_ID => SecretKey::HkdfSha512(slice.try_into()?),
+            HMACSHA256_ALG_ID => SecretKey::HmacSha256(slice.try_into()?),
+            HMACSHA512_ALG_ID => SecretKey::HmacSha512(slice.try_into()?),
+            KBKDFHMACSHA256_ALG_ID => SecretKey::KbkdfHmacSha256(slice.try_into()?),
+            X25519_ALG_ID => SecretKey::X25519(slice.try_into()?),
             _ => return Err(Error::Algorithm(alg.to_owned())),
         })
     }
 
-    /// Create a new `SecretKey` which combines multiple algorithms
+    /// Create a new `SecretKey` which combines multiple algorithms, i.e. a
+    /// KDF followed by the pipeline of algorithms it derives keys for
     pub fn new_combination(algs: &[&str], slice: &[u8]) -> Result<Self, Error> {
-        if algs.len() != 2 {
-            // can't combine more than two algorithms
+        if algs.len() < 2 {
             return Err(Error::Parse);
         }
 
-        // TODO(tarcieri): support other key derivation algorithms besides HKDF-SHA-256
-        if algs[0] != HKDFSHA256_ALG_ID {
-            return Err(Error::Algorithm(algs[0].to_owned()));
+        let kdf = algs[0].parse::<Algorithm>()?;
+        let stages = algs[1..]
+            .iter()
+            .map(|alg| alg.parse())
+            .collect::<Result<Vec<Algorithm>, Error>>()?;
+
+        let (_, new_kdf_key) = KDF_ALGORITHMS
+            .iter()
+            .find(|(alg, _)| *alg == kdf)
+            .ok_or_else(|| Error::Algorithm(algs[0].to_owned()))?;
+
+        new_kdf_key(slice, Pipeline::new(kdf, &stages)?)
+    }
+
+    /// Get the `Algorithm` for this `SecretKey`, if it is built-in
+    pub fn algorithm(&self) -> Option<Algorithm> {
+        Some(match self {
+            SecretKey::Aes128Gcm(_) => Algorithm::Aes128Gcm,
+            SecretKey::Aes256Gcm(_) => Algorithm::Aes256Gcm,
+            SecretKey::Argon2id(_) => Algorithm::Argon2id,
+            SecretKey::ChaCha20Poly1305(_) => Algorithm::ChaCha20Poly1305,
+            SecretKey::Ed25519(_) => Algorithm::Ed25519,
+            SecretKey::HkdfSha256(_) => Algorithm::HkdfSha256,
+            SecretKey::HkdfSha384(_) => Algorithm::HkdfSha384,
+            SecretKey::HkdfSha512(_) => Algorithm::HkdfSha512,
+            SecretKey::HmacSha256(_) => Algorithm::HmacSha256,
+            SecretKey::HmacSha512(_) => Algorithm::HmacSha512,
+            SecretKey::KbkdfHmacSha256(_) => Algorithm::KbkdfHmacSha256,
+            SecretKey::X25519(_) => Algorithm::X25519,
+            SecretKey::Custom(_) => return None,
+        })
+    }
+
+    /// Get the algorithm identifier for this `SecretKey`
+    pub fn alg_id(&self) -> &'static str {
+        match self {
+            SecretKey::Custom(ref custom) => custom.alg_id(),
+            _ => self
+                .algorithm()
+                .expect("built-in secret key missing algorithm")
+                .as_str(),
+        }
+    }
+
+    /// Borrow the raw bytes of this `SecretKey`
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            SecretKey::Aes128Gcm(ref key) => &key.as_ref()[..],
+            SecretKey::Aes256Gcm(ref key) => &key.as_ref()[..],
+            SecretKey::Argon2id(ref key) => key.as_ref(),
+            SecretKey::ChaCha20Poly1305(ref key) => &key.as_ref()[..],
+            SecretKey::Ed25519(ref key) => &key.as_ref()[..],
+            SecretKey::HkdfSha256(ref key) => &key.as_ref()[..],
+            SecretKey::HkdfSha384(ref key) => &key.as_ref()[..],
+            SecretKey::HkdfSha512(ref key) => &key.as_ref()[..],
+            SecretKey::HmacSha256(ref key) => key.as_ref(),
+            SecretKey::HmacSha512(ref key) => key.as_ref(),
+            SecretKey::KbkdfHmacSha256(ref key) => &key.as_ref()[..],
+            SecretKey::X25519(ref key) => &key.as_ref()[..],
+            SecretKey::Custom(ref key) => key.as_ref(),
+        }
+    }
+
+    /// Decode a `SecretKey` from a hex string using the given algorithm
+    pub fn from_hex(alg: &str, encoded: &str) -> Result<Self, Error> {
+        Self::new(alg, &Zeroizing::new(encoding::decode_hex(encoded)?))
+    }
+
+    /// Encode this `SecretKey` as a lowercase hex string.
+    ///
+    /// The result contains the raw secret key!
+    pub fn to_hex(&self) -> Zeroizing<String> {
+        Zeroizing::new(encoding::encode_hex(self.as_bytes()))
+    }
+
+    /// Decode a `SecretKey` from a base64 string using the given algorithm
+    pub fn from_base64(alg: &str, encoded: &str) -> Result<Self, Error> {
+        Self::new(alg, &Zeroizing::new(encoding::decode_base64(encoded)?))
+    }
+
+    /// Encode this `SecretKey` as a base64 string.
+    ///
+    /// The result contains the raw secret key!
+    pub fn to_base64(&self) -> Zeroizing<String> {
+        Zeroizing::new(encoding::encode_base64(self.as_bytes()))
+    }
+
+    /// Derive the `PublicKey` for this `SecretKey`, if it's an asymmetric key
+    /// whose curve implementation is enabled (i.e. the `ed25519-dalek`
+    /// feature for Ed25519, or `x25519-dalek` for X25519)
+    #[cfg(any(feature = "ed25519-dalek", feature = "x25519-dalek"))]
+    pub fn public_key(&self) -> Option<PublicKey> {
+        match self {
+            #[cfg(feature = "ed25519-dalek")]
+            SecretKey::Ed25519(ref key) => Some(PublicKey::Ed25519(key.public_key())),
+            #[cfg(feature = "x25519-dalek")]
+            SecretKey::X25519(ref key) => Some(PublicKey::X25519(key.public_key())),
+            _ => None,
+        }
+    }
+
+    /// Get the `Pipeline` of algorithms this `SecretKey` derives keys for
+    /// (only applicable to KDFs combined with other algorithms)
+    pub fn pipeline(&self) -> Option<&Pipeline> {
+        match self {
+            SecretKey::Argon2id(ref key) => key.pipeline(),
+            SecretKey::HkdfSha256(ref key) => key.pipeline(),
+            SecretKey::HkdfSha384(ref key) => key.pipeline(),
+            SecretKey::HkdfSha512(ref key) => key.pipeline(),
+            SecretKey::KbkdfHmacSha256(ref key) => key.pipeline(),
+            _ => None,
         }
+    }
 
-        let key = HkdfSha256Key::new(slice, algs[1].parse()?)?;
-        Ok(SecretKey::HkdfSha256(key))
+    /// Serialize this `SecretKey` as a URI-encoded string which is wiped
+    /// when dropped.
+    ///
+    /// Takes precedence over [`Encodable::to_uri_string`], which returns a
+    /// plain `String`.
+    pub fn to_uri_string(&self) -> Zeroizing<String> {
+        Zeroizing::new(Encodable::to_uri_string(self))
+    }
+
+    /// Serialize this `SecretKey` as a "dasherized" string which is wiped
+    /// when dropped.
+    ///
+    /// Takes precedence over [`Encodable::to_dasherized_string`], which
+    /// returns a plain `String`.
+    pub fn to_dasherized_string(&self) -> Zeroizing<String> {
+        Zeroizing::new(Encodable::to_dasherized_string(self))
+    }
+
+    /// Compare this `SecretKey` with another in constant time (with respect
+    /// to the key bytes; the algorithms and pipelines are compared in
+    /// variable time)
+    pub fn ct_eq(&self, other: &SecretKey) -> Choice {
+        Choice::from(u8::from(
+            self.alg_id() == other.alg_id() && self.pipeline() == other.pipeline(),
+        )) & self.as_bytes().ct_eq(other.as_bytes())
     }
 
     /// Return an `Aes128GcmKey` if the underlying secret key is AES-128-GCM
@@ -146,6 +571,19 @@ impl SecretKey {
         self.aes256gcm_key().is_some()
     }
 
+    /// Return an `Argon2idKey` if the underlying secret key is an Argon2id password
+    pub fn argon2id_key(&self) -> Option<&Argon2idKey> {
+        match self {
+            SecretKey::Argon2id(ref key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Is this `SecretKey` an Argon2id password?
+    pub fn is_argon2id_key(&self) -> bool {
+        self.argon2id_key().is_some()
+    }
+
     /// Return an `Ed25519SecretKey` if the underlying secret key is Ed25519
     pub fn ed25519_key(&self) -> Option<&Ed25519SecretKey> {
         match self {
@@ -171,17 +609,197 @@ impl SecretKey {
     pub fn is_hkdfsha256_key(&self) -> bool {
         self.hkdfsha256_key().is_some()
     }
+
+    /// Return an `HkdfSha384Key` if the underlying secret key is HKDF-SHA-384
+    pub fn hkdfsha384_key(&self) -> Option<&HkdfSha384Key> {
+        match self {
+            SecretKey::HkdfSha384(ref key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Is this `SecretKey` an HKDF-SHA-384 secret key?
+    pub fn is_hkdfsha384_key(&self) -> bool {
+        self.hkdfsha384_key().is_some()
+    }
+
+    /// Return an `HkdfSha512Key` if the underlying secret key is HKDF-SHA-512
+    pub fn hkdfsha512_key(&self) -> Option<&HkdfSha512Key> {
+        match self {
+            SecretKey::HkdfSha512(ref key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Is this `SecretKey` an HKDF-SHA-512 secret key?
+    pub fn is_hkdfsha512_key(&self) -> bool {
+        self.hkdfsha512_key().is_some()
+    }
+
+    /// Return an `HmacSha256Key` if the underlying secret key is HMAC-SHA-256
+    pub fn hmacsha256_key(&self) -> Option<&HmacSha256Key> {
+        match self {
+            SecretKey::HmacSha256(ref key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Is this `SecretKey` an HMAC-SHA-256 secret key?
+    pub fn is_hmacsha256_key(&self) -> bool {
+        self.hmacsha256_key().is_some()
+    }
+
+    /// Return an `HmacSha512Key` if the underlying secret key is HMAC-SHA-512
+    pub fn hmacsha512_key(&self) -> Option<&HmacSha512Key> {
+        match self {
+            SecretKey::HmacSha512(ref key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Is this `SecretKey` an HMAC-SHA-512 secret key?
+    pub fn is_hmacsha512_key(&self) -> bool {
+        self.hmacsha512_key().is_some()
+    }
+
+    /// Return a `KbkdfHmacSha256Key` if the underlying secret key is
+    /// SP 800-108 counter mode KDF with HMAC-SHA-256
+    pub fn kbkdfhmacsha256_key(&self) -> Option<&KbkdfHmacSha256Key> {
+        match self {
+            SecretKey::KbkdfHmacSha256(ref key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Is this `SecretKey` an SP 800-108 counter mode KDF secret key?
+    pub fn is_kbkdfhmacsha256_key(&self) -> bool {
+        self.kbkdfhmacsha256_key().is_some()
+    }
+
+    /// Return an `X25519SecretKey` if the underlying secret key is X25519
+    pub fn x25519_key(&self) -> Option<&X25519SecretKey> {
+        match self {
+            SecretKey::X25519(ref key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Is this `SecretKey` an X25519 secret key?
+    pub fn is_x25519_key(&self) -> bool {
+        self.x25519_key().is_some()
+    }
+
+    /// Return a `Custom` secret key if the underlying algorithm is provided
+    /// by a plugin
+    pub fn custom(&self) -> Option<&Custom> {
+        match self {
+            SecretKey::Custom(ref custom) => Some(custom),
+            _ => None,
+        }
+    }
+
+    /// Is this `SecretKey` provided by a plugin?
+    pub fn is_custom(&self) -> bool {
+        self.custom().is_some()
+    }
 }
 
+impl_try_from_crypto_uri!(
+    Aes128GcmKey,
+    SecretKey,
+    SecretKey::Aes128Gcm,
+    AES128GCM_ALG_ID
+);
+impl_try_from_crypto_uri!(
+    Aes256GcmKey,
+    SecretKey,
+    SecretKey::Aes256Gcm,
+    AES256GCM_ALG_ID
+);
+impl_try_from_crypto_uri!(Argon2idKey, SecretKey, SecretKey::Argon2id, ARGON2ID_ALG_ID);
+impl_try_from_crypto_uri!(
+    ChaCha20Poly1305Key,
+    SecretKey,
+    SecretKey::ChaCha20Poly1305,
+    CHACHA20POLY1305_ALG_ID
+);
+impl_try_from_crypto_uri!(
+    Ed25519SecretKey,
+    SecretKey,
+    SecretKey::Ed25519,
+    ED25519_ALG_ID
+);
+impl_try_from_crypto_uri!(
+    HkdfSha256Key,
+    SecretKey,
+    SecretKey::HkdfSha256,
+    HKDFSHA256_ALG_ID
+);
+impl_try_from_crypto_uri!(
+    HkdfSha384Key,
+    SecretKey,
+    SecretKey::HkdfSha384,
+    HKDFSHA384_ALG_ID
+);
+impl_try_from_crypto_uri!(
+    HkdfSha512Key,
+    SecretKey,
+    SecretKey::HkdfSha512,
+    HKDFSHA512_ALG_ID
+);
+impl_try_from_crypto_uri!(
+    HmacSha256Key,
+    SecretKey,
+    SecretKey::HmacSha256,
+    HMACSHA256_ALG_ID
+);
+impl_try_from_crypto_uri!(
+    HmacSha512Key,
+    SecretKey,
+    SecretKey::HmacSha512,
+    HMACSHA512_ALG_ID
+);
+impl_try_from_crypto_uri!(
+    KbkdfHmacSha256Key,
+    SecretKey,
+    SecretKey::KbkdfHmacSha256,
+    KBKDFHMACSHA256_ALG_ID
+);
+impl_try_from_crypto_uri!(X25519SecretKey, SecretKey, SecretKey::X25519, X25519_ALG_ID);
+
+impl_try_from_crypto_uri_kind!(SecretKey);
+
+impl ConstantTimeEq for SecretKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        SecretKey::ct_eq(self, other)
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        SecretKey::ct_eq(self, other).into()
+    }
+}
+
+impl Eq for SecretKey {}
+
 impl Encodable for SecretKey {
     /// Serialize this `SecretKey` as a URI-encoded `String`
     fn to_uri_string(&self) -> String {
         match self {
             SecretKey::Aes128Gcm(ref key) => key.to_uri_string(),
             SecretKey::Aes256Gcm(ref key) => key.to_uri_string(),
+            SecretKey::Argon2id(ref key) => key.to_uri_string(),
             SecretKey::ChaCha20Poly1305(ref key) => key.to_uri_string(),
             SecretKey::Ed25519(ref key) => key.to_uri_string(),
             SecretKey::HkdfSha256(ref key) => key.to_uri_string(),
+            SecretKey::HkdfSha384(ref key) => key.to_uri_string(),
+            SecretKey::HkdfSha512(ref key) => key.to_uri_string(),
+            SecretKey::HmacSha256(ref key) => key.to_uri_string(),
+            SecretKey::HmacSha512(ref key) => key.to_uri_string(),
+            SecretKey::KbkdfHmacSha256(ref key) => key.to_uri_string(),
+            SecretKey::X25519(ref key) => key.to_uri_string(),
+            SecretKey::Custom(ref custom) => custom.to_uri_string(),
         }
     }
 
@@ -190,9 +808,36 @@ impl Encodable for SecretKey {
         match self {
             SecretKey::Aes128Gcm(ref key) => key.to_dasherized_string(),
             SecretKey::Aes256Gcm(ref key) => key.to_dasherized_string(),
+            SecretKey::Argon2id(ref key) => key.to_dasherized_string(),
             SecretKey::ChaCha20Poly1305(ref key) => key.to_dasherized_string(),
             SecretKey::Ed25519(ref key) => key.to_dasherized_string(),
             SecretKey::HkdfSha256(ref key) => key.to_dasherized_string(),
+            SecretKey::HkdfSha384(ref key) => key.to_dasherized_string(),
+            SecretKey::HkdfSha512(ref key) => key.to_dasherized_string(),
+            SecretKey::HmacSha256(ref key) => key.to_dasherized_string(),
+            SecretKey::HmacSha512(ref key) => key.to_dasherized_string(),
+            SecretKey::KbkdfHmacSha256(ref key) => key.to_dasherized_string(),
+            SecretKey::X25519(ref key) => key.to_dasherized_string(),
+            SecretKey::Custom(ref custom) => custom.to_dasherized_string(),
+        }
+    }
+
+    /// Serialize this `SecretKey` as a URI-encoded string into the given buffer
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+        match self {
+            SecretKey::Aes128Gcm(ref key) => key.encode_into(buf),
+            SecretKey::Aes256Gcm(ref key) => key.encode_into(buf),
+            SecretKey::Argon2id(ref key) => key.encode_into(buf),
+            SecretKey::ChaCha20Poly1305(ref key) => key.encode_into(buf),
+            SecretKey::Ed25519(ref key) => key.encode_into(buf),
+            SecretKey::HkdfSha256(ref key) => key.encode_into(buf),
+            SecretKey::HkdfSha384(ref key) => key.encode_into(buf),
+            SecretKey::HkdfSha512(ref key) => key.encode_into(buf),
+            SecretKey::HmacSha256(ref key) => key.encode_into(buf),
+            SecretKey::HmacSha512(ref key) => key.encode_into(buf),
+            SecretKey::KbkdfHmacSha256(ref key) => key.encode_into(buf),
+            SecretKey::X25519(ref key) => key.encode_into(buf),
+            SecretKey::Custom(ref custom) => custom.encode_into(buf),
         }
     }
 }
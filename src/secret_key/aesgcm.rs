@@ -1,10 +1,11 @@
 //! Advanced Encryption Standard (AES - FIPS 197) in Galois/Counter Mode
 
+use super::storage::SecretBytes;
 use crate::{
     algorithm::{AES128GCM_ALG_ID, AES256GCM_ALG_ID},
     error::Error,
 };
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::ZeroizeOnDrop;
 
 /// Size of an AES-128 key in bytes
 pub const AES128_KEY_SIZE: usize = 16;
@@ -14,23 +15,25 @@ pub const AES256_KEY_SIZE: usize = 32;
 
 /// AES-128 in Galois/Counter Mode (GCM)
 #[derive(Clone)]
-pub struct Aes128GcmKey(Box<[u8; AES128_KEY_SIZE]>);
+pub struct Aes128GcmKey(SecretBytes<AES128_KEY_SIZE>);
 
 /// AES-256 in Galois/Counter Mode (GCM)
 #[derive(Clone)]
-pub struct Aes256GcmKey(Box<[u8; AES256_KEY_SIZE]>);
+pub struct Aes256GcmKey(SecretBytes<AES256_KEY_SIZE>);
 
 macro_rules! impl_aes_gcm_key {
     ($name:ident, $key_size:expr, $desc:expr) => {
-        impl AsRef<[u8; $key_size]> for $name {
-            fn as_ref(&self) -> &[u8; $key_size] {
-                &self.0
+        impl $name {
+            /// Is this key locked into memory (i.e. it can't be swapped to disk)?
+            #[cfg(feature = "mlock")]
+            pub fn is_locked(&self) -> bool {
+                self.0.is_locked()
             }
         }
 
-        impl Drop for $name {
-            fn drop(&mut self) {
-                self.0.zeroize();
+        impl AsRef<[u8; $key_size]> for $name {
+            fn as_ref(&self) -> &[u8; $key_size] {
+                self.0.as_ref()
             }
         }
 
@@ -38,9 +41,8 @@ macro_rules! impl_aes_gcm_key {
             type Error = Error;
 
             fn try_from(slice: &[u8]) -> Result<Self, Error> {
-                slice
-                    .try_into()
-                    .map(|bytes| $name(Box::new(bytes)))
+                <&[u8; $key_size]>::try_from(slice)
+                    .map(|bytes| $name(SecretBytes::new(bytes)))
                     .map_err(|_| Error::Length {
                         actual: slice.len(),
                         expected: $key_size,
@@ -49,6 +51,8 @@ macro_rules! impl_aes_gcm_key {
         }
 
         impl ZeroizeOnDrop for $name {}
+
+        impl_constant_time_eq!($name);
     };
 }
 
@@ -0,0 +1,65 @@
+//! Argon2 password hashing function (RFC 9106)
+
+use super::{Algorithm, Pipeline};
+use crate::{
+    algorithm::ARGON2ID_ALG_ID,
+    encoding::{Encodable, Encoding, DASHERIZED_ENCODING, URI_ENCODING},
+    error::Error,
+    heapless::Encoder,
+};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    string::{String, ToString},
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Minimum size of an Argon2id password
+pub const ARGON2ID_MIN_PASSWORD_SIZE: usize = 1;
+
+/// Argon2id password.
+///
+/// Passwords are variable length. The salt and cost parameters used to
+/// derive keys from them are supplied by the application.
+#[derive(Clone)]
+pub struct Argon2idKey {
+    /// Password bytes
+    ikm: Box<[u8]>,
+
+    /// Key types to derive (if specified)
+    pipeline: Option<Pipeline>,
+}
+
+impl AsRef<[u8]> for Argon2idKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.ikm
+    }
+}
+
+impl Drop for Argon2idKey {
+    fn drop(&mut self) {
+        self.ikm.zeroize();
+    }
+}
+
+impl TryFrom<&[u8]> for Argon2idKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Error> {
+        if slice.len() < ARGON2ID_MIN_PASSWORD_SIZE {
+            return Err(Error::Length {
+                actual: slice.len(),
+                expected: ARGON2ID_MIN_PASSWORD_SIZE,
+            });
+        }
+
+        Ok(Argon2idKey {
+            ikm: slice.into(),
+            pipeline: None,
+        })
+    }
+}
+
+impl ZeroizeOnDrop for Argon2idKey {}
+
+impl_kdf_key!(Argon2idKey, Algorithm::Argon2id, ARGON2ID_ALG_ID);
@@ -1,24 +1,27 @@
 //! ChaCha20Poly1305 AEAD (RFC 8439)
 
+use super::storage::SecretBytes;
 use crate::{algorithm::CHACHA20POLY1305_ALG_ID, error::Error};
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::ZeroizeOnDrop;
 
 /// Size of a ChaCha20Poly1305 key in bytes
 pub const CHACHA20POLY1305_KEY_SIZE: usize = 32;
 
 /// ChaCha20Poly1305 encryption key
 #[derive(Clone)]
-pub struct ChaCha20Poly1305Key(Box<[u8; CHACHA20POLY1305_KEY_SIZE]>);
+pub struct ChaCha20Poly1305Key(SecretBytes<CHACHA20POLY1305_KEY_SIZE>);
 
-impl AsRef<[u8; CHACHA20POLY1305_KEY_SIZE]> for ChaCha20Poly1305Key {
-    fn as_ref(&self) -> &[u8; CHACHA20POLY1305_KEY_SIZE] {
-        &self.0
+impl ChaCha20Poly1305Key {
+    /// Is this key locked into memory (i.e. it can't be swapped to disk)?
+    #[cfg(feature = "mlock")]
+    pub fn is_locked(&self) -> bool {
+        self.0.is_locked()
     }
 }
 
-impl Drop for ChaCha20Poly1305Key {
-    fn drop(&mut self) {
-        self.0.zeroize();
+impl AsRef<[u8; CHACHA20POLY1305_KEY_SIZE]> for ChaCha20Poly1305Key {
+    fn as_ref(&self) -> &[u8; CHACHA20POLY1305_KEY_SIZE] {
+        self.0.as_ref()
     }
 }
 
@@ -26,9 +29,8 @@ impl TryFrom<&[u8]> for ChaCha20Poly1305Key {
     type Error = Error;
 
     fn try_from(slice: &[u8]) -> Result<Self, Error> {
-        slice
-            .try_into()
-            .map(|bytes| ChaCha20Poly1305Key(Box::new(bytes)))
+        <&[u8; CHACHA20POLY1305_KEY_SIZE]>::try_from(slice)
+            .map(|bytes| ChaCha20Poly1305Key(SecretBytes::new(bytes)))
             .map_err(|_| Error::Length {
                 actual: slice.len(),
                 expected: 32,
@@ -38,4 +40,6 @@ impl TryFrom<&[u8]> for ChaCha20Poly1305Key {
 
 impl ZeroizeOnDrop for ChaCha20Poly1305Key {}
 
+impl_constant_time_eq!(ChaCha20Poly1305Key);
+
 impl_encodable_secret_key!(ChaCha20Poly1305Key, CHACHA20POLY1305_ALG_ID);
@@ -1,24 +1,45 @@
 //! The Ed25519 digital signature algorithm
 
+use super::storage::SecretBytes;
 use crate::{algorithm::ED25519_ALG_ID, error::Error};
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::ZeroizeOnDrop;
+
+#[cfg(feature = "ed25519-dalek")]
+use crate::{public_key::Ed25519PublicKey, signature::Ed25519Signature};
 
 /// Size of an Ed25519 secret key
 pub const ED25519_SEC_KEY_SIZE: usize = 32;
 
 /// Ed25519 secret key (i.e. private scalar)
 #[derive(Clone)]
-pub struct Ed25519SecretKey(Box<[u8; ED25519_SEC_KEY_SIZE]>);
+pub struct Ed25519SecretKey(SecretBytes<ED25519_SEC_KEY_SIZE>);
 
-impl AsRef<[u8; ED25519_SEC_KEY_SIZE]> for Ed25519SecretKey {
-    fn as_ref(&self) -> &[u8; ED25519_SEC_KEY_SIZE] {
-        &self.0
+impl Ed25519SecretKey {
+    /// Is this key locked into memory (i.e. it can't be swapped to disk)?
+    #[cfg(feature = "mlock")]
+    pub fn is_locked(&self) -> bool {
+        self.0.is_locked()
+    }
+
+    /// Derive the public key for this secret key
+    #[cfg(feature = "ed25519-dalek")]
+    pub fn public_key(&self) -> Ed25519PublicKey {
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(self.as_ref());
+        Ed25519PublicKey(signing_key.verifying_key().to_bytes())
+    }
+
+    /// Sign the given message
+    #[cfg(feature = "ed25519-dalek")]
+    pub fn sign(&self, msg: &[u8]) -> Ed25519Signature {
+        use ed25519_dalek::Signer;
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(self.as_ref());
+        Ed25519Signature(signing_key.sign(msg).to_bytes())
     }
 }
 
-impl Drop for Ed25519SecretKey {
-    fn drop(&mut self) {
-        self.0.zeroize();
+impl AsRef<[u8; ED25519_SEC_KEY_SIZE]> for Ed25519SecretKey {
+    fn as_ref(&self) -> &[u8; ED25519_SEC_KEY_SIZE] {
+        self.0.as_ref()
     }
 }
 
@@ -26,16 +47,17 @@ impl TryFrom<&[u8]> for Ed25519SecretKey {
     type Error = Error;
 
     fn try_from(slice: &[u8]) -> Result<Self, Error> {
-        slice
-            .try_into()
-            .map(|bytes| Ed25519SecretKey(Box::new(bytes)))
+        <&[u8; ED25519_SEC_KEY_SIZE]>::try_from(slice)
+            .map(|bytes| Ed25519SecretKey(SecretBytes::new(bytes)))
             .map_err(|_| Error::Length {
                 actual: slice.len(),
-                expected: 32,
+                expected: ED25519_SEC_KEY_SIZE,
             })
     }
 }
 
 impl ZeroizeOnDrop for Ed25519SecretKey {}
 
+impl_constant_time_eq!(Ed25519SecretKey);
+
 impl_encodable_secret_key!(Ed25519SecretKey, ED25519_ALG_ID);
@@ -0,0 +1,103 @@
+//! Hash-based Message Authentication Code (HMAC - RFC 2104)
+
+use crate::{
+    algorithm::{HMACSHA256_ALG_ID, HMACSHA512_ALG_ID},
+    error::Error,
+};
+use alloc::boxed::Box;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "hmac")]
+use {
+    crate::mac::{HmacSha256Tag, HmacSha512Tag},
+    hmac::Mac,
+};
+
+/// Minimum size of an HMAC-SHA-256 key in bytes (i.e. the SHA-256 output size)
+pub const HMACSHA256_MIN_KEY_SIZE: usize = 32;
+
+/// Minimum size of an HMAC-SHA-512 key in bytes (i.e. the SHA-512 output size)
+pub const HMACSHA512_MIN_KEY_SIZE: usize = 64;
+
+/// HMAC-SHA-256 secret key
+#[derive(Clone)]
+pub struct HmacSha256Key(Box<[u8]>);
+
+/// HMAC-SHA-512 secret key
+#[derive(Clone)]
+pub struct HmacSha512Key(Box<[u8]>);
+
+macro_rules! impl_hmac_key {
+    ($name:ident, $min_key_size:expr) => {
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                self.0.zeroize();
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = Error;
+
+            /// HMAC keys are variable length, but must be at least as long as
+            /// the output of the underlying hash function
+            fn try_from(slice: &[u8]) -> Result<Self, Error> {
+                if slice.len() < $min_key_size {
+                    return Err(Error::Length {
+                        actual: slice.len(),
+                        expected: $min_key_size,
+                    });
+                }
+
+                Ok($name(slice.into()))
+            }
+        }
+
+        impl ZeroizeOnDrop for $name {}
+
+        impl_constant_time_eq!($name);
+    };
+}
+
+impl_hmac_key!(HmacSha256Key, HMACSHA256_MIN_KEY_SIZE);
+impl_hmac_key!(HmacSha512Key, HMACSHA512_MIN_KEY_SIZE);
+
+#[cfg(feature = "hmac")]
+macro_rules! impl_hmac_ops {
+    ($name:ident, $digest:ty, $tag:ident) => {
+        impl $name {
+            /// Compute the HMAC tag of the given message
+            pub fn compute(&self, msg: &[u8]) -> $tag {
+                $tag(self.hmac(msg).finalize().into_bytes().into())
+            }
+
+            /// Verify the HMAC tag of the given message in constant time
+            pub fn verify(&self, msg: &[u8], tag: &$tag) -> Result<(), Error> {
+                self.hmac(msg)
+                    .verify_slice(tag.as_ref())
+                    .map_err(|_| Error::Verification)
+            }
+
+            /// Initialize HMAC with this key and feed it the given message
+            fn hmac(&self, msg: &[u8]) -> hmac::Hmac<$digest> {
+                let mut hmac = hmac::Hmac::<$digest>::new_from_slice(&self.0)
+                    .expect("HMAC accepts keys of any size");
+                hmac.update(msg);
+                hmac
+            }
+        }
+    };
+}
+
+#[cfg(feature = "hmac")]
+impl_hmac_ops!(HmacSha256Key, sha2::Sha256, HmacSha256Tag);
+#[cfg(feature = "hmac")]
+impl_hmac_ops!(HmacSha512Key, sha2::Sha512, HmacSha512Tag);
+
+impl_encodable_secret_key!(HmacSha256Key, HMACSHA256_ALG_ID);
+impl_encodable_secret_key!(HmacSha512Key, HMACSHA512_ALG_ID);
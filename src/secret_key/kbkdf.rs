@@ -0,0 +1,25 @@
+//! Key-Based Key Derivation Functions (NIST SP 800-108)
+
+use super::{storage::SecretBytes, Algorithm, Pipeline};
+use crate::{
+    algorithm::KBKDFHMACSHA256_ALG_ID,
+    encoding::{Encodable, Encoding, DASHERIZED_ENCODING, URI_ENCODING},
+    error::Error,
+    heapless::Encoder,
+};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+};
+use zeroize::ZeroizeOnDrop;
+
+/// Size of an SP 800-108 counter mode HMAC-SHA-256 secret key
+pub const KBKDFHMACSHA256_KEY_SIZE: usize = 32;
+
+fixed_size_kdf_key!(
+    KbkdfHmacSha256Key,
+    KBKDFHMACSHA256_KEY_SIZE,
+    Algorithm::KbkdfHmacSha256,
+    KBKDFHMACSHA256_ALG_ID,
+    "SP 800-108 counter mode KDF (HMAC-SHA-256 PRF) secret key"
+);
@@ -0,0 +1,64 @@
+//! Pipelines of algorithms derived from a key derivation function
+
+use super::Algorithm;
+use crate::{algorithm::Category, error::Error};
+use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
+
+/// Pipeline of algorithms to derive keys for, in order, starting from the
+/// key derivation function which a secret key is intended for.
+///
+/// For example `hkdfsha256+hkdfsha256+aes256gcm` is an HKDF-SHA-256 key
+/// with the pipeline `[HkdfSha256, Aes256Gcm]`, i.e. it derives an
+/// HKDF-SHA-256 key, which in turn derives an AES-256-GCM key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pipeline(Vec<Algorithm>);
+
+impl Pipeline {
+    /// Create a new `Pipeline` which derives keys for the given stages,
+    /// starting from a key for the given KDF.
+    ///
+    /// Every stage but the last must itself be a KDF, and the key size each
+    /// stage requires must fit within what the previous stage can output.
+    pub fn new(kdf: Algorithm, stages: &[Algorithm]) -> Result<Self, Error> {
+        let pipeline = Pipeline(stages.to_vec());
+        pipeline.validate(kdf)?;
+        Ok(pipeline)
+    }
+
+    /// Get the stages of this pipeline
+    pub fn stages(&self) -> &[Algorithm] {
+        &self.0
+    }
+
+    /// Get the algorithm of the final key this pipeline derives
+    pub fn output(&self) -> Algorithm {
+        *self.0.last().expect("pipelines are non-empty")
+    }
+
+    /// Ensure the output of each stage can be used as the input of the next,
+    /// starting with a key for the given KDF
+    pub(super) fn validate(&self, kdf: Algorithm) -> Result<(), Error> {
+        if self.0.is_empty() {
+            return Err(Error::Parse);
+        }
+
+        let mut input = kdf.info();
+
+        for stage in &self.0 {
+            let output_size = input
+                .output_size
+                .filter(|_| input.category == Category::Kdf)
+                .ok_or_else(|| Error::Algorithm(input.id.to_owned()))?;
+
+            let key_size = stage
+                .info()
+                .secret_key_size
+                .ok_or_else(|| Error::Algorithm(stage.to_string()))?;
+
+            output_size.check(key_size.minimum())?;
+            input = stage.info();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+//! Storage for fixed-size secret keys
+//!
+//! With the `mlock` feature enabled, each key is stored on a page of its own
+//! which is locked into RAM so it can't be swapped to disk, and surrounded
+//! by inaccessible guard pages so overruns of adjacent memory fault rather
+//! than reading or overwriting it (see the `cryptouri-guarded` crate, which
+//! keeps the `unsafe` code this requires out of this crate). If locking
+//! fails (e.g. because `RLIMIT_MEMLOCK` has been reached), the key is stored
+//! on the heap as usual instead.
+
+use alloc::boxed::Box;
+use zeroize::Zeroize;
+
+#[cfg(feature = "mlock")]
+use cryptouri_guarded::GuardedPage;
+
+/// Fixed-size secret bytes, zeroized on drop
+pub(crate) enum SecretBytes<const N: usize> {
+    /// Bytes stored on the heap
+    Heap(Box<[u8]>),
+
+    /// Bytes stored at the start of a locked page between two guard pages
+    #[cfg(feature = "mlock")]
+    Locked(GuardedPage),
+}
+
+impl<const N: usize> SecretBytes<N> {
+    /// Store the given bytes, locking them into memory if the `mlock`
+    /// feature is enabled
+    pub(crate) fn new(bytes: &[u8; N]) -> Self {
+        #[cfg(feature = "mlock")]
+        if let Some(locked) = Self::new_locked(bytes) {
+            return locked;
+        }
+
+        SecretBytes::Heap(Box::from(&bytes[..]))
+    }
+
+    /// Store the given bytes on a guarded page of their own and lock it
+    #[cfg(feature = "mlock")]
+    fn new_locked(bytes: &[u8; N]) -> Option<Self> {
+        let mut page = GuardedPage::new().ok()?;
+        page.as_mut_slice().get_mut(..N)?.copy_from_slice(bytes);
+        Some(SecretBytes::Locked(page))
+    }
+
+    /// Are these bytes locked into memory?
+    #[cfg(feature = "mlock")]
+    pub(crate) fn is_locked(&self) -> bool {
+        matches!(self, SecretBytes::Locked(_))
+    }
+}
+
+impl<const N: usize> AsRef<[u8; N]> for SecretBytes<N> {
+    fn as_ref(&self) -> &[u8; N] {
+        let bytes = match self {
+            SecretBytes::Heap(buf) => &buf[..N],
+            #[cfg(feature = "mlock")]
+            SecretBytes::Locked(page) => &page.as_slice()[..N],
+        };
+
+        bytes.try_into().expect("secret bytes have a fixed size")
+    }
+}
+
+impl<const N: usize> Clone for SecretBytes<N> {
+    fn clone(&self) -> Self {
+        Self::new(self.as_ref())
+    }
+}
+
+impl<const N: usize> Drop for SecretBytes<N> {
+    fn drop(&mut self) {
+        match self {
+            SecretBytes::Heap(buf) => buf.zeroize(),
+            // Zeroized by `GuardedPage` before it's unlocked and unmapped
+            #[cfg(feature = "mlock")]
+            SecretBytes::Locked(_) => (),
+        }
+    }
+}
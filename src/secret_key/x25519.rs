@@ -0,0 +1,58 @@
+//! X25519 elliptic curve Diffie-Hellman (RFC 7748)
+
+use crate::{algorithm::X25519_ALG_ID, error::Error};
+use alloc::boxed::Box;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "x25519-dalek")]
+use {crate::public_key::X25519PublicKey, zeroize::Zeroizing};
+
+/// Size of an X25519 secret key
+pub const X25519_SEC_KEY_SIZE: usize = 32;
+
+/// X25519 secret key (i.e. private scalar)
+#[derive(Clone)]
+pub struct X25519SecretKey(Box<[u8; X25519_SEC_KEY_SIZE]>);
+
+impl X25519SecretKey {
+    /// Derive the public key for this secret key
+    #[cfg(feature = "x25519-dalek")]
+    pub fn public_key(&self) -> X25519PublicKey {
+        // `StaticSecret` is zeroized on drop, but takes the key by value
+        let bytes = Zeroizing::new(*self.as_ref());
+        let secret = x25519_dalek::StaticSecret::from(*bytes);
+        X25519PublicKey(x25519_dalek::PublicKey::from(&secret).to_bytes())
+    }
+}
+
+impl AsRef<[u8; X25519_SEC_KEY_SIZE]> for X25519SecretKey {
+    fn as_ref(&self) -> &[u8; X25519_SEC_KEY_SIZE] {
+        &self.0
+    }
+}
+
+impl Drop for X25519SecretKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl TryFrom<&[u8]> for X25519SecretKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Error> {
+        slice
+            .try_into()
+            .map(|bytes| X25519SecretKey(Box::new(bytes)))
+            .map_err(|_| Error::Length {
+                actual: slice.len(),
+                expected: X25519_SEC_KEY_SIZE,
+            })
+    }
+}
+
+impl ZeroizeOnDrop for X25519SecretKey {}
+
+impl_constant_time_eq!(X25519SecretKey);
+
+impl_encodable_secret_key!(X25519SecretKey, X25519_ALG_ID);
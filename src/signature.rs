@@ -5,28 +5,92 @@ mod ed25519;
 
 pub use self::ed25519::Ed25519Signature;
 
-use crate::{algorithm::ED25519_ALG_ID, encoding::Encodable, error::Error};
-use std::convert::TryInto;
+#[cfg(feature = "alloc")]
+use {
+    crate::{
+        algorithm::{self, ED25519_ALG_ID},
+        encoding::Encodable,
+        error::Error,
+        kind::Kind,
+        plugin::Custom,
+        public_key::PublicKey,
+        secret_key::SecretKey,
+        CryptoUri,
+    },
+    alloc::{borrow::ToOwned, string::String},
+    core::convert::TryInto,
+    subtle::{Choice, ConstantTimeEq},
+};
 
 /// Signature algorithms
+#[cfg(feature = "alloc")]
 pub enum Signature {
     /// Ed25519 (RFC 8032) signature
     Ed25519(Ed25519Signature),
+
+    /// Signature using an algorithm provided by a plugin
+    Custom(Custom),
 }
 
+#[cfg(feature = "alloc")]
 impl Signature {
+    /// Parse a `Signature` from a `crypto:sig:` URI (ignoring its fragment), returning
+    /// `Error::Kind` if the URI is of a different kind
+    pub fn from_uri_str(uri: &str) -> Result<Self, Error> {
+        CryptoUri::parse_uri_of_kind(uri, Kind::Signature)?.try_into()
+    }
+
     /// Create a new `Signature` for the given algorithm
     pub fn new(alg: &str, bytes: &[u8]) -> Result<Self, Error> {
+        if algorithm::lookup(alg).is_none() {
+            return Ok(Signature::Custom(Custom::new(Kind::Signature, alg, bytes)?));
+        }
+
+        algorithm::validate(alg, Kind::Signature, bytes.len())?;
+
         match alg {
             ED25519_ALG_ID => Ok(Signature::Ed25519(bytes.try_into()?)),
             _ => Err(Error::Algorithm(alg.to_owned())),
         }
     }
 
+    /// Sign the given message using the given `SecretKey`.
+    ///
+    /// Requires the `ed25519-dalek` cargo feature, otherwise
+    /// `Error::Algorithm` is returned.
+    #[cfg_attr(not(feature = "ed25519-dalek"), allow(unused_variables))]
+    pub fn sign(key: &SecretKey, msg: &[u8]) -> Result<Self, Error> {
+        match key {
+            #[cfg(feature = "ed25519-dalek")]
+            SecretKey::Ed25519(ref key) => Ok(Signature::Ed25519(key.sign(msg))),
+            _ => Err(Error::Algorithm(key.alg_id().to_owned())),
+        }
+    }
+
+    /// Verify that this `Signature` over the given message was made by the
+    /// secret key for the given `PublicKey`
+    #[cfg_attr(not(feature = "ed25519-dalek"), allow(unused_variables))]
+    pub fn verify(&self, key: &PublicKey, msg: &[u8]) -> Result<(), Error> {
+        match (self, key) {
+            #[cfg(feature = "ed25519-dalek")]
+            (Signature::Ed25519(ref sig), PublicKey::Ed25519(ref key)) => key.verify(msg, sig),
+            _ => Err(Error::Algorithm(key.alg_id().to_owned())),
+        }
+    }
+
+    /// Get the algorithm identifier for this `Signature`
+    pub fn alg_id(&self) -> &'static str {
+        match self {
+            Signature::Ed25519(_) => ED25519_ALG_ID,
+            Signature::Custom(ref custom) => custom.alg_id(),
+        }
+    }
+
     /// Return an `Ed25519Signature` if the underlying signature is Ed25519
     pub fn ed25519_signature(&self) -> Option<&Ed25519Signature> {
         match self {
             Signature::Ed25519(ref sig) => Some(sig),
+            _ => None,
         }
     }
 
@@ -34,13 +98,69 @@ impl Signature {
     pub fn is_ed25519_signature(&self) -> bool {
         self.ed25519_signature().is_some()
     }
+
+    /// Return a `Custom` signature if the underlying algorithm is provided
+    /// by a plugin
+    pub fn custom(&self) -> Option<&Custom> {
+        match self {
+            Signature::Custom(ref custom) => Some(custom),
+            _ => None,
+        }
+    }
+
+    /// Is this `Signature` provided by a plugin?
+    pub fn is_custom(&self) -> bool {
+        self.custom().is_some()
+    }
+}
+
+impl_try_from_crypto_uri!(
+    Ed25519Signature,
+    Signature,
+    Signature::Ed25519,
+    ED25519_ALG_ID
+);
+
+#[cfg(feature = "alloc")]
+impl_try_from_crypto_uri_kind!(Signature);
+#[cfg(feature = "alloc")]
+impl_hex_base64!(Signature);
+
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Signature::Ed25519(ref sig) => sig.as_ref(),
+            Signature::Custom(ref custom) => custom.as_ref(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ConstantTimeEq for Signature {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Choice::from(u8::from(self.alg_id() == other.alg_id()))
+            & self.as_ref().ct_eq(other.as_ref())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for Signature {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
 }
 
+#[cfg(feature = "alloc")]
+impl Eq for Signature {}
+
+#[cfg(feature = "alloc")]
 impl Encodable for Signature {
     /// Serialize this `Signature` as a URI-encoded `String`
     fn to_uri_string(&self) -> String {
         match self {
             Signature::Ed25519(ref sig) => sig.to_uri_string(),
+            Signature::Custom(ref custom) => custom.to_uri_string(),
         }
     }
 
@@ -48,6 +168,15 @@ impl Encodable for Signature {
     fn to_dasherized_string(&self) -> String {
         match self {
             Signature::Ed25519(ref sig) => sig.to_dasherized_string(),
+            Signature::Custom(ref custom) => custom.to_dasherized_string(),
+        }
+    }
+
+    /// Serialize this `Signature` as a URI-encoded string into the given buffer
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+        match self {
+            Signature::Ed25519(ref sig) => sig.encode_into(buf),
+            Signature::Custom(ref custom) => custom.encode_into(buf),
         }
     }
 }
@@ -34,4 +34,5 @@ impl AsRef<[u8]> for Ed25519Signature {
     }
 }
 
+impl_constant_time_eq!(Ed25519Signature);
 impl_encodable_signature!(Ed25519Signature, ED25519_ALG_ID);
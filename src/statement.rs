@@ -0,0 +1,279 @@
+//! Signed statements: a hash signed by a signer, encoded as one CryptoURI
+//!
+//! Statements have their own `crypto:stmt:` scheme. Their algorithm combines
+//! the signature, hash and signer algorithms, and their data is the
+//! signature, digest and signer (i.e. public key or fingerprint)
+//! concatenated, e.g.:
+//!
+//! ```text
+//! crypto:stmt:ed25519+sha256+ed25519:<signature><digest><public key>
+//! ```
+//!
+//! Whether the signer is a public key or a fingerprint is determined by its
+//! algorithm: the signer of `ed25519+sha256+sha256` is a fingerprint.
+//!
+//! The signature is over the hash's CryptoURI (e.g. `crypto:hash:sha256:...`)
+//! rather than the bare digest, so it also authenticates the hash algorithm.
+
+use crate::{
+    algorithm::{self, Length},
+    encoding::{Encodable, Encoding, DASHERIZED_ENCODING, URI_ENCODING},
+    error::Error,
+    hash::Hash,
+    kind::Kind,
+    plugin,
+    public_key::PublicKey,
+    signature::Signature,
+    CryptoUri,
+};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::convert::TryInto;
+use subtle::ConstantTimeEq;
+use subtle_encoding::bech32::{self, Bech32};
+
+#[cfg(feature = "ed25519-dalek")]
+use crate::key_pair::KeyPair;
+
+/// Signer of a `Statement`
+pub enum Signer {
+    /// Signer's public key
+    PublicKey(PublicKey),
+
+    /// Fingerprint of the signer's public key (see
+    /// `PublicKey::fingerprint`)
+    Fingerprint(Hash),
+}
+
+impl Signer {
+    /// Get the algorithm identifier for this `Signer`
+    pub fn alg_id(&self) -> &'static str {
+        match self {
+            Signer::PublicKey(ref key) => key.alg_id(),
+            Signer::Fingerprint(ref fingerprint) => fingerprint.alg_id(),
+        }
+    }
+
+    /// Does this `Signer` identify the given `PublicKey`?
+    fn matches(&self, key: &PublicKey) -> bool {
+        match self {
+            Signer::PublicKey(ref signer) => {
+                signer.alg_id() == key.alg_id() && bool::from(signer.as_ref().ct_eq(key.as_ref()))
+            }
+            #[cfg(feature = "sha2")]
+            Signer::Fingerprint(ref fingerprint) => key.fingerprint().ct_eq(fingerprint).into(),
+            #[cfg(not(feature = "sha2"))]
+            Signer::Fingerprint(_) => false,
+        }
+    }
+}
+
+impl AsRef<[u8]> for Signer {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Signer::PublicKey(ref key) => key.as_ref(),
+            Signer::Fingerprint(ref fingerprint) => fingerprint.as_ref(),
+        }
+    }
+}
+
+/// Statement that the signer signed a hash (e.g. of a release artifact)
+pub struct Statement {
+    /// Hash which was signed
+    hash: Hash,
+
+    /// Signature over the hash's CryptoURI
+    signature: Signature,
+
+    /// Signer's public key or its fingerprint
+    signer: Signer,
+}
+
+impl Statement {
+    /// Create a new `Statement` from its parts. The signature isn't verified.
+    pub fn new(hash: Hash, signature: Signature, signer: Signer) -> Self {
+        Self {
+            hash,
+            signature,
+            signer,
+        }
+    }
+
+    /// Sign the given hash with the given `KeyPair`, embedding its public key
+    #[cfg(feature = "ed25519-dalek")]
+    pub fn sign(key_pair: &KeyPair, hash: Hash) -> Result<Self, Error> {
+        let signature = Signature::sign(key_pair.secret_key(), signed_message(&hash).as_bytes())?;
+        let public_key = PublicKey::new(
+            key_pair.public_key().alg_id(),
+            key_pair.public_key().as_ref(),
+        )?;
+        Ok(Self::new(hash, signature, Signer::PublicKey(public_key)))
+    }
+
+    /// Parse a `Statement` from a `crypto:stmt:` URI (ignoring its
+    /// fragment), returning `Error::Kind` if the URI is of a different kind
+    pub fn parse_uri(uri: &str) -> Result<Self, Error> {
+        CryptoUri::parse_uri_of_kind(uri, Kind::Statement)?.try_into()
+    }
+
+    /// Parse a `Statement` in URI-embeddable (a.k.a. "dasherized") encoding
+    pub fn parse_dasherized(token: &str) -> Result<Self, Error> {
+        CryptoUri::parse_dasherized(token)?.try_into()
+    }
+
+    /// Create a `Statement` from the combined algorithm identifier (in the
+    /// given encoding) and data of a `crypto:stmt:` URI
+    pub(crate) fn new_combination(
+        alg_id: &str,
+        data: &[u8],
+        encoding: &Encoding,
+    ) -> Result<Self, Error> {
+        let alg_ids = alg_id.split(encoding.combine).collect::<Vec<_>>();
+
+        let (sig_alg, hash_alg, signer_alg) = match alg_ids.as_slice() {
+            [sig_alg, hash_alg, signer_alg] => (*sig_alg, *hash_alg, *signer_alg),
+            _ => return Err(Error::Algorithm(alg_id.to_owned())),
+        };
+
+        let signer_kind = if fixed_length(signer_alg, Kind::PublicKey).is_ok() {
+            Kind::PublicKey
+        } else {
+            Kind::Hash
+        };
+
+        let sig_len = fixed_length(sig_alg, Kind::Signature)?;
+        let hash_len = fixed_length(hash_alg, Kind::Hash)?;
+        let signer_len = fixed_length(signer_alg, signer_kind)?;
+
+        if data.len() != sig_len + hash_len + signer_len {
+            return Err(Error::Length {
+                actual: data.len(),
+                expected: sig_len + hash_len + signer_len,
+            });
+        }
+
+        let (sig_bytes, rest) = data.split_at(sig_len);
+        let (hash_bytes, signer_bytes) = rest.split_at(hash_len);
+
+        let signer = match signer_kind {
+            Kind::PublicKey => Signer::PublicKey(PublicKey::new(signer_alg, signer_bytes)?),
+            _ => Signer::Fingerprint(Hash::new(signer_alg, signer_bytes)?),
+        };
+
+        Ok(Self {
+            hash: Hash::new(hash_alg, hash_bytes)?,
+            signature: Signature::new(sig_alg, sig_bytes)?,
+            signer,
+        })
+    }
+
+    /// Get the hash which was signed
+    pub fn hash(&self) -> &Hash {
+        &self.hash
+    }
+
+    /// Get the signature over the hash
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Get the signer
+    pub fn signer(&self) -> &Signer {
+        &self.signer
+    }
+
+    /// Verify the signature over the hash using the embedded public key.
+    ///
+    /// Returns `Error::Verification` if the signer is only identified by
+    /// its fingerprint: use [`Statement::verify_with`] instead.
+    pub fn verify(&self) -> Result<(), Error> {
+        match self.signer {
+            Signer::PublicKey(ref key) => self
+                .signature
+                .verify(key, signed_message(&self.hash).as_bytes()),
+            Signer::Fingerprint(_) => Err(Error::Verification),
+        }
+    }
+
+    /// Verify the signature over the hash using the given `PublicKey`, which
+    /// must be the signer's (or match the signer's fingerprint, which
+    /// requires the `sha2` cargo feature)
+    pub fn verify_with(&self, key: &PublicKey) -> Result<(), Error> {
+        if !self.signer.matches(key) {
+            return Err(Error::Verification);
+        }
+
+        self.signature
+            .verify(key, signed_message(&self.hash).as_bytes())
+    }
+
+    /// Verify that the hash is the digest of the given data, and the
+    /// signature over it using the embedded public key
+    pub fn verify_data(&self, data: &[u8]) -> Result<(), Error> {
+        self.hash.verify(data)?;
+        self.verify()
+    }
+
+    /// Get the combined algorithm identifier for this `Statement` in the
+    /// given encoding
+    pub(crate) fn alg_id(&self, encoding: &Encoding) -> String {
+        let mut alg_id = String::from(self.signature.alg_id());
+        alg_id.push(encoding.combine);
+        alg_id.push_str(self.hash.alg_id());
+        alg_id.push(encoding.combine);
+        alg_id.push_str(self.signer.alg_id());
+        alg_id
+    }
+
+    /// Get the data for this `Statement`, i.e. the signature, digest and
+    /// signer concatenated
+    pub(crate) fn data(&self) -> Vec<u8> {
+        [
+            self.signature.as_ref(),
+            self.hash.as_ref(),
+            self.signer.as_ref(),
+        ]
+        .concat()
+    }
+
+    /// Encode this `Statement` using the given encoding
+    fn encode(&self, encoding: &Encoding) -> String {
+        Bech32::new(bech32::DEFAULT_CHARSET, encoding.delimiter).encode(
+            String::from(encoding.statement_scheme) + &self.alg_id(encoding),
+            self.data(),
+        )
+    }
+}
+
+impl Encodable for Statement {
+    /// Serialize this `Statement` as a URI-encoded `String`
+    fn to_uri_string(&self) -> String {
+        self.encode(URI_ENCODING)
+    }
+
+    /// Serialize this `Statement` as a "dasherized" `String`
+    fn to_dasherized_string(&self) -> String {
+        self.encode(DASHERIZED_ENCODING)
+    }
+}
+
+/// Get the message which is signed for the given hash: its CryptoURI, so
+/// the signature also covers the hash algorithm
+fn signed_message(hash: &Hash) -> String {
+    hash.to_uri_string()
+}
+
+/// Get the fixed length of the data of the given kind for the given
+/// algorithm (which may be provided by a plugin)
+fn fixed_length(alg_id: &str, kind: Kind) -> Result<usize, Error> {
+    let length = match algorithm::lookup(alg_id) {
+        Some(info) => info.length(kind),
+        None => plugin::lookup(alg_id).and_then(|plugin| plugin.length(kind)),
+    };
+
+    match length {
+        Some(Length::Fixed(len)) => Ok(len),
+        _ => Err(Error::Algorithm(alg_id.to_owned())),
+    }
+}
+
+impl_try_from_crypto_uri_kind!(Statement);
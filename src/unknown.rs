@@ -0,0 +1,126 @@
+//! Opaque CryptoURIs using algorithms this crate doesn't know about
+
+use crate::{
+    algorithm,
+    encoding::{self, Encodable, Encoding, DASHERIZED_ENCODING, URI_ENCODING},
+    error::Error,
+    kind::Kind,
+    parts::Parts,
+    plugin,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::mem;
+use zeroize::Zeroize;
+
+/// CryptoURI with a valid scheme but an algorithm which is neither built in
+/// nor provided by a plugin.
+///
+/// Only returned by the lenient parsing methods (e.g.
+/// [`CryptoUri::parse_uri_lenient`][crate::CryptoUri::parse_uri_lenient]).
+/// Serializes back to the same string it was parsed from.
+#[derive(Clone)]
+pub struct Unknown {
+    /// Kind of CryptoURI
+    kind: Kind,
+
+    /// Algorithm identifier (combined algorithms are joined with `+`)
+    alg_id: String,
+
+    /// Data (zeroized on drop, as it may be a secret key)
+    bytes: Vec<u8>,
+}
+
+impl Unknown {
+    /// Create an `Unknown` from the given parts if its scheme and algorithm
+    /// identifiers are well-formed, but any of its algorithms are unknown
+    pub(crate) fn from_parts(parts: &Parts, encoding: &Encoding) -> Option<Self> {
+        let kind = [
+            Kind::Hash,
+            Kind::Mac,
+            Kind::PublicKey,
+            Kind::SecretKey,
+            Kind::Signature,
+            Kind::Statement,
+        ]
+        .iter()
+        .copied()
+        .find(|&kind| parts.prefix.starts_with(encoding.scheme(kind)))?;
+
+        let alg_id = &parts.prefix[encoding.scheme(kind).len()..];
+
+        let is_known =
+            |alg: &str| algorithm::lookup(alg).is_some() || plugin::lookup(alg).is_some();
+
+        if !alg_id.split(encoding.combine).all(algorithm::is_valid_id)
+            || alg_id.split(encoding.combine).all(is_known)
+        {
+            return None;
+        }
+
+        Some(Unknown {
+            kind,
+            alg_id: alg_id.replace(encoding.combine, &URI_ENCODING.combine.to_string()),
+            bytes: parts.data.clone(),
+        })
+    }
+
+    /// Get the kind of CryptoURI this object is
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Get the algorithm identifier for this object (combined algorithms
+    /// are joined with `+`)
+    pub fn alg_id(&self) -> &str {
+        &self.alg_id
+    }
+
+    /// Encode this object using the given encoding
+    fn encode(&self, encoding: &Encoding) -> String {
+        let alg_id = self
+            .alg_id
+            .replace(URI_ENCODING.combine, &encoding.combine.to_string());
+
+        // May be a secret key
+        mem::take(&mut *encoding::encode_zeroizing(
+            encoding,
+            self.kind,
+            &alg_id,
+            &self.bytes[..],
+        ))
+    }
+}
+
+impl AsRef<[u8]> for Unknown {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Drop for Unknown {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl Encodable for Unknown {
+    fn to_uri_string(&self) -> String {
+        self.encode(URI_ENCODING)
+    }
+
+    fn to_dasherized_string(&self) -> String {
+        self.encode(DASHERIZED_ENCODING)
+    }
+
+    fn encode_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, Error> {
+        encoding::encode_into(
+            buf,
+            URI_ENCODING.scheme(self.kind),
+            &self.alg_id,
+            &self.bytes,
+        )
+    }
+}
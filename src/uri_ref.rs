@@ -0,0 +1,146 @@
+//! Zero-copy borrowed view of a CryptoURI
+
+use crate::{
+    encoding::{Encoding, DASHERIZED_ENCODING, URI_ENCODING},
+    error::Error,
+    heapless,
+    kind::Kind,
+    parts::Parts,
+    CryptoUri,
+};
+use alloc::{borrow::ToOwned, vec};
+use core::fmt::{self, Display};
+
+/// Borrowed view of a CryptoURI which has had its Bech32 checksum verified,
+/// but whose data is only decoded on request.
+///
+/// Its algorithm isn't checked against the [`algorithm`][crate::algorithm]
+/// registry: use [`CryptoUriRef::to_crypto_uri`] to fully parse it.
+#[derive(Copy, Clone, Debug)]
+pub struct CryptoUriRef<'a> {
+    /// Original URI (including the fragment, if any)
+    uri: &'a str,
+
+    /// Kind of CryptoURI
+    kind: Kind,
+
+    /// Bech32 human-readable part (i.e. scheme and algorithm identifier)
+    hrp: &'a str,
+
+    /// Algorithm identifier (as written)
+    alg_id: &'a str,
+
+    /// Verified Bech32 payload, excluding the checksum
+    payload: &'a str,
+
+    /// URI fragment (i.e. everything after `#`)
+    fragment: Option<&'a str>,
+
+    /// Encoding the URI was parsed from
+    encoding: &'static Encoding,
+}
+
+impl<'a> CryptoUriRef<'a> {
+    /// Parse a `CryptoUriRef`, verifying its checksum
+    pub fn parse_uri(uri: &'a str) -> Result<Self, Error> {
+        Self::parse(uri, URI_ENCODING)
+    }
+
+    /// Parse a `CryptoUriRef` in URI-embeddable (a.k.a. "dasherized")
+    /// encoding, verifying its checksum
+    pub fn parse_dasherized(token: &'a str) -> Result<Self, Error> {
+        Self::parse(token, DASHERIZED_ENCODING)
+    }
+
+    /// Parse a `CryptoUriRef` using the given encoding
+    fn parse(uri: &'a str, encoding: &'static Encoding) -> Result<Self, Error> {
+        let (encoded, fragment) = heapless::split_fragment(uri, encoding);
+        let (hrp, payload) = heapless::verify(encoded, encoding.delimiter)?;
+
+        let kind = [
+            Kind::Hash,
+            Kind::Mac,
+            Kind::PublicKey,
+            Kind::SecretKey,
+            Kind::Signature,
+            Kind::Statement,
+        ]
+        .iter()
+        .copied()
+        .find(|&kind| {
+            let scheme = encoding.scheme(kind);
+            hrp.len() > scheme.len()
+                && hrp.is_char_boundary(scheme.len())
+                && hrp[..scheme.len()].eq_ignore_ascii_case(scheme)
+        })
+        .ok_or_else(|| Error::Scheme(hrp.to_owned()))?;
+
+        Ok(Self {
+            uri,
+            kind,
+            hrp,
+            alg_id: &hrp[encoding.scheme(kind).len()..],
+            payload,
+            fragment,
+            encoding,
+        })
+    }
+
+    /// Get the original URI this view borrows from
+    pub fn as_str(&self) -> &'a str {
+        self.uri
+    }
+
+    /// Get the kind of CryptoURI
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Get the algorithm identifier as written (i.e. combined algorithms are
+    /// joined with `_` in the "dasherized" encoding)
+    pub fn alg_id(&self) -> &'a str {
+        self.alg_id
+    }
+
+    /// Iterate over the identifiers of the combined algorithms (e.g. a KDF
+    /// and the key it derives), or the single algorithm if not combined
+    pub fn algorithms(&self) -> impl Iterator<Item = &'a str> {
+        self.alg_id.split(self.encoding.combine)
+    }
+
+    /// Get the URI fragment (i.e. everything after `#`)
+    pub fn fragment(&self) -> Option<&'a str> {
+        self.fragment
+    }
+
+    /// Get the length of the data (i.e. key, digest, tag or signature)
+    pub fn data_len(&self) -> usize {
+        heapless::payload_len(self.payload)
+    }
+
+    /// Decode the data into `out` without allocating. Its length must be
+    /// [`CryptoUriRef::data_len`].
+    pub fn decode_into(&self, out: &mut [u8]) -> Result<(), Error> {
+        heapless::decode_payload(self.payload, out)
+    }
+
+    /// Fully parse this view as a `CryptoUri`
+    pub fn to_crypto_uri(&self) -> Result<CryptoUri, Error> {
+        let mut data = vec![0u8; self.data_len()];
+        self.decode_into(&mut data)?;
+
+        let parts = Parts {
+            prefix: self.hrp.to_ascii_lowercase(),
+            data,
+            fragment: self.fragment.map(ToOwned::to_owned),
+        };
+
+        CryptoUri::from_parts(&parts, self.encoding, false)
+    }
+}
+
+impl<'a> Display for CryptoUriRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.uri)
+    }
+}
@@ -0,0 +1,57 @@
+//! age recipient and identity conversion tests
+
+#![cfg(feature = "age")]
+
+use cryptouri::{PublicKey, SecretKey};
+
+const IDENTITY: &str = "AGE-SECRET-KEY-1QYPQXPQ9QCRSSZG2PVXQ6RS0ZQG3YYC5Z5TPWXQERGD3C8G7RUSQGPQYEE";
+
+const RECIPIENT: &str = "age1q73he0q5yzfu3d64msd3p6rvksnrwjk3d2598mgtmlqt9wrdr37q2vrn72";
+
+const SECRET_KEY_HEX: &str = "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+
+const PUBLIC_KEY_HEX: &str = "07a37cbc142093c8b755dc1b10e86cb426374ad16aa853ed0bdfc0b2b86d1c7c";
+
+#[test]
+fn recipient_round_trip() {
+    let key = PublicKey::from_age_recipient(RECIPIENT).unwrap();
+    assert!(key.is_x25519_key());
+    assert_eq!(key.to_hex(), PUBLIC_KEY_HEX);
+    assert_eq!(key.to_age_recipient().unwrap(), RECIPIENT);
+}
+
+#[test]
+fn identity_round_trip() {
+    let key = SecretKey::from_age_identity(IDENTITY).unwrap();
+    assert!(key.is_x25519_key());
+    assert_eq!(key.to_hex().as_str(), SECRET_KEY_HEX);
+    assert_eq!(key.to_age_identity().unwrap().as_str(), IDENTITY);
+}
+
+#[test]
+fn identity_file() {
+    let file = format!(
+        "# created: 2024-01-01T00:00:00Z\n# public key: {}\n{}\n",
+        RECIPIENT, IDENTITY
+    );
+
+    let key = SecretKey::from_age_identity(&file).unwrap();
+    assert_eq!(key.to_hex().as_str(), SECRET_KEY_HEX);
+}
+
+#[test]
+fn reject_wrong_prefix() {
+    assert!(PublicKey::from_age_recipient(&IDENTITY.to_lowercase()).is_err());
+    assert!(SecretKey::from_age_identity(&RECIPIENT.to_uppercase()).is_err());
+}
+
+#[test]
+fn reject_other_algorithms() {
+    let key = PublicKey::from_hex(
+        "ed25519",
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+    )
+    .unwrap();
+
+    assert!(key.to_age_recipient().is_err());
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "alloc")]
+
+use cryptouri::{
+    algorithm::{self, Category, Length, ALGORITHMS},
+    secret_key::Algorithm,
+    Error, Hash, Kind, PublicKey, SecretKey,
+};
+
+#[test]
+fn lookup() {
+    let info = algorithm::lookup("ed25519").unwrap();
+    assert_eq!(info.category, Category::Signature);
+    assert_eq!(info.public_key_size, Some(Length::Fixed(32)));
+    assert_eq!(info.output_size, Some(Length::Fixed(64)));
+    assert!(info.supports(Kind::Signature));
+    assert!(!info.supports(Kind::Hash));
+}
+
+#[test]
+fn lookup_by_external_names() {
+    assert_eq!(algorithm::lookup_oid("1.3.101.112").unwrap().id, "ed25519");
+    assert_eq!(algorithm::lookup_jose("HS256").unwrap().id, "hmacsha256");
+    assert_eq!(algorithm::lookup_cose(3).unwrap().id, "aes256gcm");
+}
+
+#[test]
+fn registry_ids_are_unique() {
+    for (i, info) in ALGORITHMS.iter().enumerate() {
+        assert!(ALGORITHMS[(i + 1)..]
+            .iter()
+            .all(|other| other.id != info.id));
+    }
+}
+
+#[test]
+fn secret_key_algorithms_are_registered() {
+    for info in ALGORITHMS
+        .iter()
+        .filter(|info| info.supports(Kind::SecretKey))
+    {
+        let alg: Algorithm = info.id.parse().unwrap();
+        assert_eq!(alg.info(), info);
+    }
+}
+
+/// Algorithms are rejected for kinds of CryptoURI they can't appear in
+#[test]
+fn reject_unsupported_kind() {
+    assert_eq!(
+        SecretKey::new("sha256", &[0; 32]).err(),
+        Some(Error::Algorithm("sha256".to_owned()))
+    );
+    assert_eq!(
+        PublicKey::new("aes256gcm", &[0; 32]).err(),
+        Some(Error::Algorithm("aes256gcm".to_owned()))
+    );
+    assert_eq!(
+        Hash::new("ed25519", &[0; 64]).err(),
+        Some(Error::Algorithm("ed25519".to_owned()))
+    );
+}
+
+#[test]
+fn length_rules() {
+    assert!(Length::Fixed(32).check(32).is_ok());
+    assert!(Length::Min(32).check(64).is_ok());
+    assert_eq!(
+        Length::Min(32).check(16),
+        Err(Error::Length {
+            actual: 16,
+            expected: 32
+        })
+    );
+    assert!(Length::Max(32).check(33).is_err());
+}
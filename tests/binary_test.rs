@@ -0,0 +1,131 @@
+//! Compact binary encoding tests
+
+#![cfg(feature = "alloc")]
+
+use cryptouri::{CryptoUri, Encodable, Error};
+
+const HASH_URI: &str =
+    "crypto:hash:sha256:hfupd0u0q8875s2pgr09mt3zywcqxcdrjcth4895zrlkrusqzkkschwfca";
+
+const PIPELINE_URI: &str =
+    "crypto:sec:key:hkdfsha256+aes256gcm:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9sxm0sk0";
+
+const UNKNOWN_URI: &str = "crypto:sec:key:hkdfsha256+xchacha20poly1305:qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z5tpwxqergd3c8g7rusqr309u4";
+
+/// Round trip a URI through the binary encoding
+fn round_trip(uri: &str) -> CryptoUri {
+    let bytes = CryptoUri::parse_uri(uri).unwrap().to_bytes();
+    let decoded = CryptoUri::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.to_uri_string(), uri);
+    decoded
+}
+
+#[test]
+fn hash() {
+    let bytes = CryptoUri::parse_uri(HASH_URI).unwrap().to_bytes();
+
+    // Tag, one algorithm (SHA-256), and the 32-byte digest
+    assert_eq!(&bytes[..4], &[0x01, 0x01, 0x10, 0x20]);
+    assert_eq!(bytes.len(), 36);
+
+    round_trip(HASH_URI);
+}
+
+#[test]
+fn fragment() {
+    let uri = format!("{}#release-2024", HASH_URI);
+    let bytes = CryptoUri::parse_uri(&uri).unwrap().to_bytes();
+    assert_eq!(bytes[0], 0x81);
+
+    let decoded = round_trip(&uri);
+    assert_eq!(decoded.fragment(), Some("release-2024"));
+}
+
+#[test]
+fn pipeline() {
+    let decoded = round_trip(PIPELINE_URI);
+    assert_eq!(
+        decoded.to_dasherized_string(),
+        CryptoUri::parse_uri(PIPELINE_URI)
+            .unwrap()
+            .to_dasherized_string()
+    );
+}
+
+#[test]
+fn unknown_algorithm() {
+    let bytes = CryptoUri::parse_uri_lenient(UNKNOWN_URI)
+        .unwrap()
+        .to_bytes();
+
+    assert!(CryptoUri::from_bytes(&bytes).is_err());
+
+    let decoded = CryptoUri::from_bytes_lenient(&bytes).unwrap();
+    assert!(decoded.is_unknown());
+    assert_eq!(decoded.to_uri_string(), UNKNOWN_URI);
+}
+
+#[test]
+fn reject_malformed() {
+    let bytes = CryptoUri::parse_uri(HASH_URI).unwrap().to_bytes();
+
+    assert!(CryptoUri::from_bytes(&[]).is_err());
+    assert!(CryptoUri::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(CryptoUri::from_bytes(&[&bytes[..], &[0]].concat()).is_err());
+
+    let mut bad_kind = bytes.to_vec();
+    bad_kind[0] = 0x7f;
+    assert!(CryptoUri::from_bytes(&bad_kind).is_err());
+}
+
+#[test]
+fn reject_malformed_alg_id() {
+    for alg_id in [&b"FOO"[..], b"a b", b"a+b", b"a:b", "ä".as_bytes()] {
+        let mut bytes = vec![0x01, 1, 0, alg_id.len() as u8];
+        bytes.extend_from_slice(alg_id);
+        bytes.extend_from_slice(&[1, 0]);
+
+        assert_eq!(
+            CryptoUri::from_bytes_lenient(&bytes).err().unwrap(),
+            Error::Parse
+        );
+    }
+}
+
+#[test]
+fn reject_non_minimal_varint() {
+    let bytes = CryptoUri::parse_uri(HASH_URI).unwrap().to_bytes();
+
+    // Algorithm count of 1 encoded as `0x81 0x00` rather than `0x01`
+    let mut padded = vec![bytes[0], 0x81, 0x00];
+    padded.extend_from_slice(&bytes[2..]);
+    assert_eq!(CryptoUri::from_bytes(&padded).err().unwrap(), Error::Parse);
+}
+
+#[test]
+fn reject_named_algorithm_with_code() {
+    let bytes = CryptoUri::parse_uri(HASH_URI).unwrap().to_bytes();
+
+    // SHA-256 encoded by name rather than its code (`0x10`)
+    let mut named = vec![bytes[0], 0x01, 0x00, 0x06];
+    named.extend_from_slice(b"sha256");
+    named.extend_from_slice(&bytes[3..]);
+    assert_eq!(CryptoUri::from_bytes(&named).err().unwrap(), Error::Parse);
+    assert_eq!(
+        CryptoUri::from_bytes_lenient(&named).err().unwrap(),
+        Error::Parse
+    );
+}
+
+#[test]
+fn reject_unknown_algorithm_code() {
+    let mut bytes = CryptoUri::parse_uri(HASH_URI).unwrap().to_bytes();
+    bytes[2] = 0x7f;
+
+    let expected = Error::Algorithm("0x7f".to_owned());
+    assert_eq!(CryptoUri::from_bytes(&bytes).err().unwrap(), expected);
+    assert_eq!(
+        CryptoUri::from_bytes_lenient(&bytes).err().unwrap(),
+        expected
+    );
+}
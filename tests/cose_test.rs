@@ -0,0 +1,125 @@
+//! COSE_Key conversion tests
+
+#![cfg(feature = "cose")]
+
+/// Ed25519 keys and signatures
+///
+/// Uses the RFC 8032 "TEST 1" test vector
+/// https://tools.ietf.org/html/rfc8032#section-7.1
+mod ed25519 {
+    use cryptouri::{CryptoUri, Encodable, Error, PublicKey, SecretKey, Signature};
+
+    const PUBLIC_KEY_URI: &str =
+        "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv";
+
+    const PUBLIC_KEY_BYTES: &[u8] = &[
+        215, 90, 152, 1, 130, 177, 10, 183, 213, 75, 254, 211, 201, 100, 7, 58, 14, 225, 114, 243,
+        218, 166, 35, 37, 175, 2, 26, 104, 247, 7, 81, 26,
+    ];
+
+    const SECRET_KEY_BYTES: &[u8] = &[
+        157, 97, 177, 157, 239, 253, 90, 96, 186, 132, 74, 244, 146, 236, 44, 196, 68, 73, 197,
+        105, 123, 50, 105, 25, 112, 59, 172, 3, 28, 174, 127, 96,
+    ];
+
+    /// `{1: 1, 3: -8, -1: 6, -2: h'...'}`
+    const PUBLIC_KEY_COSE_PREFIX: &[u8] =
+        &[0xa4, 0x01, 0x01, 0x03, 0x27, 0x20, 0x06, 0x21, 0x58, 0x20];
+
+    /// `{1: 1, 3: -8, -1: 6, -4: h'...'}`
+    const SECRET_KEY_COSE_PREFIX: &[u8] =
+        &[0xa4, 0x01, 0x01, 0x03, 0x27, 0x20, 0x06, 0x23, 0x58, 0x20];
+
+    #[test]
+    fn public_key_to_cose_key() {
+        let uri = CryptoUri::parse_uri(PUBLIC_KEY_URI).unwrap();
+        let cose_key = uri.public_key().unwrap().to_cose_key().unwrap();
+        assert_eq!(&cose_key[..10], PUBLIC_KEY_COSE_PREFIX);
+        assert_eq!(&cose_key[10..], PUBLIC_KEY_BYTES);
+    }
+
+    #[test]
+    fn public_key_from_cose_key() {
+        let cose_key = [PUBLIC_KEY_COSE_PREFIX, PUBLIC_KEY_BYTES].concat();
+        let key = PublicKey::from_cose_key(&cose_key).unwrap();
+        assert_eq!(key.to_uri_string(), PUBLIC_KEY_URI);
+    }
+
+    #[test]
+    fn secret_key_round_trip() {
+        let key = SecretKey::new("ed25519", SECRET_KEY_BYTES).unwrap();
+        let cose_key = key.to_cose_key().unwrap();
+        assert_eq!(&cose_key[..10], SECRET_KEY_COSE_PREFIX);
+
+        let decoded = SecretKey::from_cose_key(&cose_key).unwrap();
+        assert_eq!(decoded.ed25519_key().unwrap().as_ref(), SECRET_KEY_BYTES);
+    }
+
+    #[test]
+    fn secret_key_with_public_key() {
+        // `{1: 1, 3: -8, -1: 6, -2: h'...', -4: h'...'}`
+        let cose_key = |public_key: &[u8]| {
+            [
+                &[0xa5, 0x01, 0x01, 0x03, 0x27, 0x20, 0x06, 0x21, 0x58, 0x20][..],
+                public_key,
+                &[0x23, 0x58, 0x20],
+                SECRET_KEY_BYTES,
+            ]
+            .concat()
+        };
+
+        let decoded = SecretKey::from_cose_key(&cose_key(PUBLIC_KEY_BYTES)).unwrap();
+        assert_eq!(decoded.ed25519_key().unwrap().as_ref(), SECRET_KEY_BYTES);
+
+        assert_eq!(
+            SecretKey::from_cose_key(&cose_key(&[0x42; 32]))
+                .err()
+                .unwrap(),
+            Error::Verification
+        );
+    }
+
+    #[test]
+    fn public_key_rejects_secret_key() {
+        let key = SecretKey::new("ed25519", SECRET_KEY_BYTES).unwrap();
+        assert!(PublicKey::from_cose_key(&key.to_cose_key().unwrap()).is_err());
+    }
+
+    #[test]
+    fn signature() {
+        let sig = Signature::from_cose(-8, &[0x42; 64]).unwrap();
+        assert!(sig.is_ed25519_signature());
+        assert_eq!(sig.cose_alg().unwrap(), -8);
+        assert_eq!(sig.as_ref(), &[0x42; 64][..]);
+    }
+
+    #[test]
+    fn signature_unknown_alg() {
+        assert!(Signature::from_cose(-7, &[0x42; 64]).is_err());
+    }
+}
+
+/// Symmetric keys
+mod symmetric {
+    use cryptouri::SecretKey;
+
+    /// `{1: 4, 3: 3, -1: h'...'}`
+    const AES256GCM_COSE_PREFIX: &[u8] = &[0xa3, 0x01, 0x04, 0x03, 0x03, 0x20, 0x58, 0x20];
+
+    #[test]
+    fn aes256gcm_round_trip() {
+        let key = SecretKey::new("aes256gcm", &[0x42; 32]).unwrap();
+        let cose_key = key.to_cose_key().unwrap();
+        assert_eq!(&cose_key[..8], AES256GCM_COSE_PREFIX);
+        assert_eq!(&cose_key[8..], &[0x42; 32]);
+
+        let decoded = SecretKey::from_cose_key(&cose_key).unwrap();
+        assert_eq!(decoded.to_uri_string(), key.to_uri_string());
+    }
+
+    #[test]
+    fn algorithm_without_cose_label() {
+        let key = SecretKey::new("kbkdfhmacsha256", &[0x42; 32]).unwrap();
+        assert!(key.to_cose_key().is_err());
+    }
+}
@@ -1 +1,31 @@
-// TODO: actually write fingerprint test!
+//! Public key fingerprint tests
+
+#![cfg(all(feature = "alloc", feature = "sha2"))]
+
+use cryptouri::{CryptoUri, Encodable, Hash};
+
+/// Public key from RFC 8032 test vector: "TEST 1" secret key
+const EXAMPLE_PUBLIC_KEY: &str =
+    "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv";
+
+const EXAMPLE_FINGERPRINT: &str =
+    "crypto:hash:sha256:xq5mu3zcx58l8qssk249ataxtjh4njka3nv9k0te7uue2z2vzeds7lqleg";
+
+#[test]
+fn fingerprint() {
+    let uri = CryptoUri::parse_uri(EXAMPLE_PUBLIC_KEY).unwrap();
+    let fingerprint = uri.public_key().unwrap().fingerprint();
+    assert_eq!(fingerprint.to_uri_string(), EXAMPLE_FINGERPRINT);
+}
+
+#[test]
+fn fingerprint_is_digest_of_uri() {
+    let uri = CryptoUri::parse_uri(EXAMPLE_PUBLIC_KEY).unwrap();
+    let fingerprint = uri.public_key().unwrap().fingerprint();
+    assert_eq!(
+        fingerprint.as_ref(),
+        Hash::compute("sha256", EXAMPLE_PUBLIC_KEY.as_bytes())
+            .unwrap()
+            .as_ref()
+    );
+}
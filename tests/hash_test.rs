@@ -0,0 +1,198 @@
+#![cfg(feature = "alloc")]
+
+/// Hash tests
+///
+/// All test vectors are digests of the message "abc"
+macro_rules! hash_test {
+    ($name:ident, $hashtype:ident, $feature:literal, $uri:expr, $dasherized:expr, $bytes:expr) => {
+        mod $name {
+            use cryptouri::hash::$hashtype;
+            use cryptouri::{CryptoUri, Encodable};
+            use std::convert::TryFrom;
+
+            #[test]
+            fn parse_uri() {
+                let hash = CryptoUri::parse_uri($uri).unwrap();
+                assert_eq!(hash.hash().unwrap().$name().unwrap().as_ref(), $bytes);
+            }
+
+            #[test]
+            fn parse_dasherized() {
+                let hash = CryptoUri::parse_dasherized($dasherized).unwrap();
+                assert_eq!(hash.hash().unwrap().$name().unwrap().as_ref(), $bytes);
+            }
+
+            #[test]
+            fn serialize_uri() {
+                let hash = $hashtype::try_from($bytes.as_ref()).unwrap();
+                assert_eq!(&hash.to_uri_string(), $uri);
+            }
+
+            #[test]
+            fn serialize_dasherized() {
+                let hash = $hashtype::try_from($bytes.as_ref()).unwrap();
+                assert_eq!(&hash.to_dasherized_string(), $dasherized);
+            }
+
+            #[cfg(feature = $feature)]
+            #[test]
+            fn compute() {
+                assert_eq!($hashtype::digest(b"abc").as_ref(), $bytes);
+            }
+
+            #[cfg(feature = $feature)]
+            #[test]
+            fn verify() {
+                let hash = CryptoUri::parse_uri($uri).unwrap();
+                assert!(hash.hash().unwrap().verify(b"abc").is_ok());
+                assert!(hash.hash().unwrap().verify(b"abd").is_err());
+            }
+        }
+    };
+}
+
+hash_test!(
+    sha256_digest,
+    Sha256Hash,
+    "sha2",
+    "crypto:hash:sha256:hfupd0u0q8875s2pgr09mt3zywcqxcdrjcth4895zrlkrusqzkkschwfca",
+    "crypto-hash-sha256-hfupd0u0q8875s2pgr09mt3zywcqxcdrjcth4895zrlkrusqzkksj6rryu",
+    &[
+        186, 120, 22, 191, 143, 1, 207, 234, 65, 65, 64, 222, 93, 174, 34, 35, 176, 3, 97, 163,
+        150, 23, 122, 156, 180, 16, 255, 97, 242, 0, 21, 173
+    ]
+);
+
+hash_test!(
+    sha384_digest,
+    Sha384Hash,
+    "sha2",
+    "crypto:hash:sha384:evq820695d0ghddq845e43jsqunjcv4tpm0dzcc63ds95sllt0kcpps89ws70nprtzawegf5eqj6wzmplyj",
+    "crypto-hash-sha384-evq820695d0ghddq845e43jsqunjcv4tpm0dzcc63ds95sllt0kcpps89ws70nprtzawegf5eqj6w50pppd",
+    &[
+        203, 0, 117, 63, 69, 163, 94, 139, 181, 160, 61, 105, 154, 198, 80, 7, 39, 44, 50, 171,
+        14, 222, 209, 99, 26, 139, 96, 90, 67, 255, 91, 237, 128, 134, 7, 43, 161, 231, 204, 35,
+        88, 186, 236, 161, 52, 200, 37, 167
+    ]
+);
+
+hash_test!(
+    sha512_digest,
+    Sha512Hash,
+    "sha2",
+    "crypto:hash:sha512:mkhntgvnv9at4nzpwdy6ugzpxyfwd7jw3x5hags2nmhwvj646wdzry5e9gn5lsdgx6arcgarlm4m632dgs3kg08gpc4f4j2054x2f8c97wck2",
+    "crypto-hash-sha512-mkhntgvnv9at4nzpwdy6ugzpxyfwd7jw3x5hags2nmhwvj646wdzry5e9gn5lsdgx6arcgarlm4m632dgs3kg08gpc4f4j2054x2f8csmhlh7",
+    &[
+        221, 175, 53, 161, 147, 97, 122, 186, 204, 65, 115, 73, 174, 32, 65, 49, 18, 230, 250, 78,
+        137, 169, 126, 162, 10, 158, 238, 230, 75, 85, 211, 154, 33, 146, 153, 42, 39, 79, 193,
+        168, 54, 186, 60, 35, 163, 254, 235, 189, 69, 77, 68, 35, 100, 60, 232, 14, 42, 154, 201,
+        79, 165, 76, 164, 159
+    ]
+);
+
+hash_test!(
+    sha512t256_digest,
+    Sha512t256Hash,
+    "sha2",
+    "crypto:hash:sha512t256:2vzguf5pjs00nxew9xmkknra40jv95xxxn7x63hqutcnzpl84u3sgksyvq",
+    "crypto-hash-sha512t256-2vzguf5pjs00nxew9xmkknra40jv95xxxn7x63hqutcnzpl84u3s5nr6gz",
+    &[
+        83, 4, 142, 38, 129, 148, 30, 249, 155, 46, 41, 183, 107, 76, 125, 171, 228, 194, 208, 198,
+        52, 252, 109, 70, 224, 226, 241, 49, 7, 231, 175, 35
+    ]
+);
+
+hash_test!(
+    sha3_256_digest,
+    Sha3_256Hash,
+    "sha3",
+    "crypto:hash:sha3-256:82v9mf60ugjmypzuzukkh5ushkz47zrw86w4yk6xhl3y2y2rz5eqa2x2c6",
+    "crypto-hash-sha3-256-82v9mf60ugjmypzuzukkh5ushkz47zrw86w4yk6xhl3y2y2rz5eqp94xg6",
+    &[
+        58, 152, 93, 167, 79, 226, 37, 178, 4, 92, 23, 45, 107, 211, 144, 189, 133, 95, 8, 110, 62,
+        157, 82, 91, 70, 191, 226, 69, 17, 67, 21, 50
+    ]
+);
+
+hash_test!(
+    blake2b_digest,
+    Blake2bHash,
+    "blake2",
+    "crypto:hash:blake2b:h2q220ucr3xs6638j7mf7yhka9xzztc5dpdvfd6tz2aklkll5tghmp798y42k7fdcffdthj9x0xf2xxn325dhuvjt2uj8phd6sqfjgc8tn3k7",
+    "crypto-hash-blake2b-h2q220ucr3xs6638j7mf7yhka9xzztc5dpdvfd6tz2aklkll5tghmp798y42k7fdcffdthj9x0xf2xxn325dhuvjt2uj8phd6sqfjgc902m99",
+    &[
+        186, 128, 165, 63, 152, 28, 77, 13, 106, 39, 151, 182, 159, 18, 246, 233, 76, 33, 47, 20,
+        104, 90, 196, 183, 75, 18, 187, 111, 219, 255, 162, 209, 125, 135, 197, 57, 42, 171, 121,
+        45, 194, 82, 213, 222, 69, 51, 204, 149, 24, 211, 138, 168, 219, 241, 146, 90, 185, 35,
+        134, 237, 212, 0, 153, 35
+    ]
+);
+
+hash_test!(
+    blake3_digest,
+    Blake3Hash,
+    "blake3",
+    "crypto:hash:blake3:vsmm8tpcgegn8lak8d6jww5dk4yv2kzxt4uakqlaxkwxe4dankzs8eqe2t",
+    "crypto-hash-blake3-vsmm8tpcgegn8lak8d6jww5dk4yv2kzxt4uakqlaxkwxe4dankzsd5dnk2",
+    &[
+        100, 55, 179, 172, 56, 70, 81, 51, 255, 182, 59, 117, 39, 58, 141, 181, 72, 197, 88, 70,
+        93, 121, 219, 3, 253, 53, 156, 108, 213, 189, 157, 133
+    ]
+);
+
+/// Hex and base64 conversions
+mod hex_base64 {
+    use cryptouri::{Encodable, Hash};
+
+    const EXAMPLE_URI: &str =
+        "crypto:hash:sha256:hfupd0u0q8875s2pgr09mt3zywcqxcdrjcth4895zrlkrusqzkkschwfca";
+
+    const EXAMPLE_HEX: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    const EXAMPLE_BASE64: &str = "ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0=";
+
+    #[test]
+    fn from_hex() {
+        let hash = Hash::from_hex("sha256", EXAMPLE_HEX).unwrap();
+        assert_eq!(hash.to_uri_string(), EXAMPLE_URI);
+
+        let upper = Hash::from_hex("sha256", &EXAMPLE_HEX.to_uppercase()).unwrap();
+        assert_eq!(upper.to_hex(), EXAMPLE_HEX);
+    }
+
+    #[test]
+    fn from_base64() {
+        let hash = Hash::from_base64("sha256", EXAMPLE_BASE64).unwrap();
+        assert_eq!(hash.to_uri_string(), EXAMPLE_URI);
+        assert_eq!(hash.to_base64(), EXAMPLE_BASE64);
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert!(Hash::from_hex("sha512", EXAMPLE_HEX).is_err());
+    }
+
+    #[test]
+    fn invalid_hex() {
+        assert!(Hash::from_hex("sha256", "not hex").is_err());
+    }
+}
+
+/// Constant-time equality
+mod constant_time_eq {
+    use cryptouri::Hash;
+
+    const EXAMPLE_HEX: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    #[test]
+    fn ct_eq() {
+        let hash = Hash::from_hex("sha256", EXAMPLE_HEX).unwrap();
+        let same = Hash::from_hex("sha256", EXAMPLE_HEX).unwrap();
+        let other_alg = Hash::from_hex("sha3-256", EXAMPLE_HEX).unwrap();
+
+        assert!(bool::from(hash.ct_eq(&same)));
+        assert!(!bool::from(hash.ct_eq(&other_alg)));
+        assert!(hash == same);
+        assert!(hash != other_alg);
+    }
+}
@@ -0,0 +1,113 @@
+//! Allocation-free parsing and encoding tests
+
+use cryptouri::{hash::Sha256Hash, public_key::Ed25519PublicKey, Encodable, Error};
+
+#[cfg(feature = "alloc")]
+use cryptouri::CryptoUri;
+
+const PUBLIC_KEY_URI: &str =
+    "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv";
+
+const PUBLIC_KEY_DASHERIZED: &str =
+    "crypto-pub-key-ed25519-6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqlu986g";
+
+const PUBLIC_KEY_BYTES: [u8; 32] = [
+    215, 90, 152, 1, 130, 177, 10, 183, 213, 75, 254, 211, 201, 100, 7, 58, 14, 225, 114, 243, 218,
+    166, 35, 37, 175, 2, 26, 104, 247, 7, 81, 26,
+];
+
+const HASH_URI: &str =
+    "crypto:hash:sha256:hfupd0u0q8875s2pgr09mt3zywcqxcdrjcth4895zrlkrusqzkkschwfca";
+
+#[cfg(feature = "alloc")]
+const PIPELINE_URI: &str =
+    "crypto:sec:key:hkdfsha256+aes256gcm:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9sxm0sk0";
+
+#[test]
+fn parse_public_key() {
+    let key = Ed25519PublicKey::parse_uri(PUBLIC_KEY_URI).unwrap();
+    assert_eq!(key.0, PUBLIC_KEY_BYTES);
+
+    let key = Ed25519PublicKey::parse_dasherized(PUBLIC_KEY_DASHERIZED).unwrap();
+    assert_eq!(key.0, PUBLIC_KEY_BYTES);
+}
+
+#[test]
+fn parse_ignores_fragment() {
+    let uri = format!("{}#release-key", PUBLIC_KEY_URI);
+    let key = Ed25519PublicKey::parse_uri(&uri).unwrap();
+    assert_eq!(key.0, PUBLIC_KEY_BYTES);
+}
+
+#[test]
+fn parse_hash() {
+    let hash = Sha256Hash::parse_uri(HASH_URI).unwrap();
+    assert_eq!(&hash.0[..4], &[0xba, 0x78, 0x16, 0xbf]);
+}
+
+#[test]
+fn reject_wrong_type() {
+    assert_eq!(
+        Sha256Hash::parse_uri(PUBLIC_KEY_URI).err(),
+        Some(Error::Parse)
+    );
+    assert!(Ed25519PublicKey::parse_uri(HASH_URI).is_err());
+}
+
+#[test]
+fn reject_bad_checksum() {
+    let uri = PUBLIC_KEY_URI.replace("f03cvv", "f03cvw");
+    assert_eq!(
+        Ed25519PublicKey::parse_uri(&uri).err(),
+        Some(Error::Checksum)
+    );
+}
+
+#[test]
+fn encode_into() {
+    let key = Ed25519PublicKey::parse_uri(PUBLIC_KEY_URI).unwrap();
+    let mut buf = [0u8; 128];
+    assert_eq!(key.encode_into(&mut buf).unwrap(), PUBLIC_KEY_URI);
+}
+
+#[test]
+fn encode_into_buffer_too_small() {
+    let key = Ed25519PublicKey::parse_uri(PUBLIC_KEY_URI).unwrap();
+    let mut buf = [0u8; 64];
+
+    assert_eq!(
+        key.encode_into(&mut buf).err(),
+        Some(Error::Length {
+            actual: 64,
+            expected: PUBLIC_KEY_URI.len()
+        })
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_into_secret_key_pipeline() {
+    let uri = CryptoUri::parse_uri(PIPELINE_URI).unwrap();
+    let key = uri.secret_key().unwrap();
+    let mut buf = [0u8; 128];
+    assert_eq!(key.encode_into(&mut buf).unwrap(), PIPELINE_URI);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_into_with_fragment() {
+    let uri = format!("{}#release-key", HASH_URI);
+    let crypto_uri = CryptoUri::parse_uri(&uri).unwrap();
+
+    let mut buf = [0u8; 128];
+    assert_eq!(crypto_uri.encode_into(&mut buf).unwrap(), uri);
+
+    let mut buf = [0u8; 80];
+    assert_eq!(
+        crypto_uri.encode_into(&mut buf).err(),
+        Some(Error::Length {
+            actual: 80,
+            expected: uri.len()
+        })
+    );
+}
@@ -0,0 +1,215 @@
+//! JSON Web Key conversion tests
+
+#![cfg(feature = "jwk")]
+
+/// Ed25519 keys
+///
+/// Uses examples from RFC 8037 Appendix A
+/// https://tools.ietf.org/html/rfc8037#appendix-A
+mod ed25519 {
+    use cryptouri::{CryptoUri, Encodable, PublicKey, SecretKey};
+
+    const PUBLIC_KEY_URI: &str =
+        "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv";
+
+    const PUBLIC_KEY_JWK: &str =
+        r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+
+    const PRIVATE_KEY_JWK: &str = r#"{"kty":"OKP","crv":"Ed25519","d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+
+    const PRIVATE_KEY_BYTES: &[u8] = &[
+        157, 97, 177, 157, 239, 253, 90, 96, 186, 132, 74, 244, 146, 236, 44, 196, 68, 73, 197,
+        105, 123, 50, 105, 25, 112, 59, 172, 3, 28, 174, 127, 96,
+    ];
+
+    #[test]
+    fn public_key_from_jwk() {
+        let key = PublicKey::from_jwk(PUBLIC_KEY_JWK).unwrap();
+        assert_eq!(key.to_uri_string(), PUBLIC_KEY_URI);
+    }
+
+    #[test]
+    fn public_key_to_jwk() {
+        let uri = CryptoUri::parse_uri(PUBLIC_KEY_URI).unwrap();
+        let key = uri.public_key().unwrap();
+        let jwk: serde_json::Value = serde_json::from_str(&key.to_jwk().unwrap()).unwrap();
+
+        assert_eq!(jwk["kty"], "OKP");
+        assert_eq!(jwk["crv"], "Ed25519");
+        assert_eq!(jwk["alg"], "EdDSA");
+        assert_eq!(jwk["x"], "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo");
+        assert_eq!(jwk["kid"], key.fingerprint().to_uri_string());
+    }
+
+    #[test]
+    fn secret_key_from_jwk() {
+        let key = SecretKey::from_jwk(PRIVATE_KEY_JWK).unwrap();
+        assert_eq!(key.ed25519_key().unwrap().as_ref(), PRIVATE_KEY_BYTES);
+    }
+
+    #[test]
+    fn public_key_rejects_private_jwk() {
+        assert!(PublicKey::from_jwk(PRIVATE_KEY_JWK).is_err());
+    }
+
+    #[test]
+    fn crypto_uri_fragment() {
+        let uri = CryptoUri::parse_uri(&format!("{}#alice", PUBLIC_KEY_URI)).unwrap();
+        let jwk = uri.to_jwk().unwrap();
+
+        let decoded = CryptoUri::from_jwk(&jwk).unwrap();
+        assert_eq!(decoded.fragment(), Some("alice"));
+        assert_eq!(decoded.to_uri_string(), format!("{}#alice", PUBLIC_KEY_URI));
+    }
+
+    #[test]
+    fn crypto_uri_secret_key() {
+        let uri = CryptoUri::from_jwk(PRIVATE_KEY_JWK).unwrap();
+        assert!(uri.secret_key().unwrap().is_ed25519_key());
+    }
+
+    #[test]
+    fn secret_key_round_trip() {
+        let key = SecretKey::new("ed25519", PRIVATE_KEY_BYTES).unwrap();
+        let jwk = key.to_jwk().unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&jwk).unwrap();
+        assert_eq!(json["kty"], "OKP");
+        assert_eq!(json["crv"], "Ed25519");
+        assert_eq!(json["alg"], "EdDSA");
+        assert_eq!(json["d"], "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A");
+        assert_eq!(json["x"], "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo");
+
+        let decoded = SecretKey::from_jwk(&jwk).unwrap();
+        assert_eq!(decoded.to_uri_string(), key.to_uri_string());
+    }
+
+    #[test]
+    fn secret_key_rejects_mismatched_public_key() {
+        let jwk = PRIVATE_KEY_JWK.replace("11qYAYKx", "21qYAYKx");
+        assert!(SecretKey::from_jwk(&jwk).is_err());
+    }
+}
+
+/// X25519 keys
+///
+/// Uses Alice's public key from RFC 7748 Section 6.1
+mod x25519 {
+    use cryptouri::{PublicKey, SecretKey};
+
+    const PUBLIC_KEY_JWK: &str =
+        r#"{"kty":"OKP","crv":"X25519","x":"hSDwCYkwp1R0i33ctD73Wg2_Og0mOBr066SpjqqbTmo"}"#;
+
+    const PRIVATE_KEY_BYTES: &[u8] = &[
+        0x77, 0x07, 0x6d, 0x0a, 0x73, 0x18, 0xa5, 0x7d, 0x3c, 0x16, 0xc1, 0x72, 0x51, 0xb2, 0x66,
+        0x45, 0xdf, 0x4c, 0x2f, 0x87, 0xeb, 0xc0, 0x99, 0x2a, 0xb1, 0x77, 0xfb, 0xa5, 0x1d, 0xb9,
+        0x2c, 0x2a,
+    ];
+
+    #[test]
+    fn public_key_round_trip() {
+        let key = PublicKey::from_jwk(PUBLIC_KEY_JWK).unwrap();
+        assert!(key.is_x25519_key());
+
+        let jwk: serde_json::Value = serde_json::from_str(&key.to_jwk().unwrap()).unwrap();
+        assert_eq!(jwk["crv"], "X25519");
+        assert_eq!(jwk["x"], "hSDwCYkwp1R0i33ctD73Wg2_Og0mOBr066SpjqqbTmo");
+
+        // `ECDH-ES` is a key agreement mode rather than a key type
+        assert!(jwk.get("alg").is_none());
+    }
+
+    #[test]
+    fn secret_key_round_trip() {
+        let key = SecretKey::new("x25519", PRIVATE_KEY_BYTES).unwrap();
+        let jwk = key.to_jwk().unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&jwk).unwrap();
+        assert_eq!(json["kty"], "OKP");
+        assert_eq!(json["crv"], "X25519");
+        assert_eq!(json["d"], "dwdtCnMYpX08FsFyUbJmRd9ML4frwJkqsXf7pR25LCo");
+        assert_eq!(json["x"], "hSDwCYkwp1R0i33ctD73Wg2_Og0mOBr066SpjqqbTmo");
+
+        let decoded = SecretKey::from_jwk(&jwk).unwrap();
+        assert_eq!(decoded.to_uri_string(), key.to_uri_string());
+    }
+}
+
+/// Symmetric (`oct`) keys
+mod oct {
+    use cryptouri::{CryptoUri, Error, SecretKey};
+
+    #[test]
+    fn aes256gcm_round_trip() {
+        let key = SecretKey::new("aes256gcm", &[0x42; 32]).unwrap();
+        let jwk = key.to_jwk().unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&jwk).unwrap();
+        assert_eq!(json["kty"], "oct");
+        assert_eq!(json["alg"], "dir");
+        assert_eq!(json["cryptouri_alg"], "aes256gcm");
+        assert_eq!(json["k"], "QkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkI");
+
+        let decoded = SecretKey::from_jwk(&jwk).unwrap();
+        assert_eq!(decoded.to_uri_string(), key.to_uri_string());
+    }
+
+    #[test]
+    fn hmac_round_trip() {
+        let key = SecretKey::new("hmacsha256", &[0x42; 32]).unwrap();
+        let jwk = key.to_jwk().unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&jwk).unwrap();
+        assert_eq!(json["alg"], "HS256");
+
+        let decoded = SecretKey::from_jwk(&jwk).unwrap();
+        assert_eq!(decoded.to_uri_string(), key.to_uri_string());
+
+        // A JWS `alg` identifies the algorithm on its own
+        let jwk =
+            r#"{"kty":"oct","alg":"HS256","k":"QkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkI"}"#;
+        assert!(SecretKey::from_jwk(jwk).unwrap().is_hmacsha256_key());
+    }
+
+    #[test]
+    fn alg_without_jose_name() {
+        let key = SecretKey::new("hkdfsha256", &[0x42; 32]).unwrap();
+        let jwk = key.to_jwk().unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&jwk).unwrap();
+        assert!(json.get("alg").is_none());
+        assert_eq!(json["cryptouri_alg"], "hkdfsha256");
+
+        let uri = CryptoUri::from_jwk(&jwk).unwrap();
+        assert!(uri.secret_key().unwrap().is_hkdfsha256_key());
+    }
+
+    #[test]
+    fn reject_non_jose_alg() {
+        for alg in ["hkdfsha256", "A256GCM", "dir"] {
+            let jwk = format!(
+                r#"{{"kty":"oct","alg":"{}","k":"QkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkI"}}"#,
+                alg
+            );
+            assert_eq!(
+                SecretKey::from_jwk(&jwk).err().unwrap(),
+                Error::Algorithm(alg.to_owned())
+            );
+        }
+    }
+
+    #[test]
+    fn reject_mismatched_alg() {
+        let jwk = r#"{"kty":"oct","alg":"HS256","cryptouri_alg":"aes256gcm","k":"QkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkI"}"#;
+        assert!(matches!(
+            SecretKey::from_jwk(jwk).err().unwrap(),
+            Error::Algorithm(_)
+        ));
+    }
+
+    #[test]
+    fn kdf_pipeline_export_unsupported() {
+        let uri = CryptoUri::parse_uri("crypto:sec:key:hkdfsha256+aes256gcm:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9sxm0sk0").unwrap();
+        assert!(uri.secret_key().unwrap().to_jwk().is_err());
+    }
+}
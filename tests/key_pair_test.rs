@@ -0,0 +1,82 @@
+//! Key pair tests
+
+#![cfg(all(feature = "alloc", feature = "ed25519-dalek"))]
+
+use cryptouri::{Encodable, Error, KeyPair, SecretKey};
+
+/// RFC 8032 test vector: "TEST 1" secret key
+const ED25519_SECRET_KEY: [u8; 32] = [
+    0x9d, 0x61, 0xb1, 0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec, 0x2c, 0xc4,
+    0x44, 0x49, 0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03, 0x1c, 0xae, 0x7f, 0x60,
+];
+
+/// Public key from RFC 8032 test vector: "TEST 1" secret key
+const ED25519_PUBLIC_KEY: &str =
+    "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv";
+
+/// SHA-256 fingerprint of `ED25519_PUBLIC_KEY`
+#[cfg(feature = "sha2")]
+const ED25519_FINGERPRINT: &str =
+    "crypto:hash:sha256:xq5mu3zcx58l8qssk249ataxtjh4njka3nv9k0te7uue2z2vzeds7lqleg";
+
+#[test]
+fn ed25519_public_key() {
+    let secret_key = SecretKey::new("ed25519", &ED25519_SECRET_KEY).unwrap();
+    let public_key = secret_key.public_key().unwrap();
+    assert_eq!(public_key.to_uri_string(), ED25519_PUBLIC_KEY);
+}
+
+#[test]
+fn symmetric_key_has_no_public_key() {
+    let secret_key = SecretKey::new("aes256gcm", &[0x42; 32]).unwrap();
+    assert!(secret_key.public_key().is_none());
+    assert!(matches!(
+        KeyPair::new(secret_key).err().unwrap(),
+        Error::Algorithm(_)
+    ));
+}
+
+#[test]
+fn key_pair_round_trip() {
+    let secret_key = SecretKey::new("ed25519", &ED25519_SECRET_KEY).unwrap();
+    let uri = secret_key.to_uri_string();
+
+    let key_pair = KeyPair::from_uri_str(&uri).unwrap();
+    assert_eq!(key_pair.to_uri_string().as_str(), uri.as_str());
+    assert_eq!(
+        Encodable::to_dasherized_string(&key_pair),
+        Encodable::to_dasherized_string(&secret_key)
+    );
+    assert_eq!(key_pair.public_key().to_uri_string(), ED25519_PUBLIC_KEY);
+}
+
+#[cfg(feature = "sha2")]
+#[test]
+fn key_pair_fingerprint() {
+    let key_pair = KeyPair::new(SecretKey::new("ed25519", &ED25519_SECRET_KEY).unwrap()).unwrap();
+    assert_eq!(key_pair.fingerprint().to_uri_string(), ED25519_FINGERPRINT);
+}
+
+#[cfg(feature = "x25519-dalek")]
+#[test]
+fn x25519_public_key() {
+    // RFC 7748 section 6.1: Alice's private and public keys
+    let secret_key = SecretKey::new(
+        "x25519",
+        &[
+            0x77, 0x07, 0x6d, 0x0a, 0x73, 0x18, 0xa5, 0x7d, 0x3c, 0x16, 0xc1, 0x72, 0x51, 0xb2,
+            0x66, 0x45, 0xdf, 0x4c, 0x2f, 0x87, 0xeb, 0xc0, 0x99, 0x2a, 0xb1, 0x77, 0xfb, 0xa5,
+            0x1d, 0xb9, 0x2c, 0x2a,
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(
+        secret_key.public_key().unwrap().as_ref(),
+        &[
+            0x85, 0x20, 0xf0, 0x09, 0x89, 0x30, 0xa7, 0x54, 0x74, 0x8b, 0x7d, 0xdc, 0xb4, 0x3e,
+            0xf7, 0x5a, 0x0d, 0xbf, 0x3a, 0x0d, 0x26, 0x38, 0x1a, 0xf4, 0xeb, 0xa4, 0xa9, 0x8e,
+            0xaa, 0x9b, 0x4e, 0x6a,
+        ]
+    );
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "alloc")]
+
+/// HMAC-SHA-256 tag test
+///
+/// Tag is for the message "Hi There" under a 32-byte key of `0x0b` bytes
+mod hmacsha256 {
+    use cryptouri::mac::HmacSha256Tag;
+    use cryptouri::{CryptoUri, Encodable};
+    use std::convert::TryInto;
+
+    const EXAMPLE_URI: &str =
+        "crypto:mac:hmacsha256:rx9xql45f0audxgr5rcu72aackaq4glnmxhrc8r68vtfdg9k3nmsnftj24";
+
+    const EXAMPLE_DASHERIZED: &str =
+        "crypto-mac-hmacsha256-rx9xql45f0audxgr5rcu72aackaq4glnmxhrc8r68vtfdg9k3nmsyfp935";
+
+    const EXAMPLE_BYTES: &[u8] = &[
+        25, 138, 96, 126, 180, 75, 251, 198, 153, 3, 160, 241, 207, 43, 189, 197, 186, 10, 163,
+        243, 217, 174, 60, 28, 122, 59, 22, 150, 160, 182, 140, 247,
+    ];
+
+    #[cfg(feature = "hmac")]
+    const EXAMPLE_KEY: &str =
+        "crypto:sec:key:hmacsha256:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9s47lm50";
+
+    #[test]
+    fn parse_uri() {
+        let mac = CryptoUri::parse_uri(EXAMPLE_URI).unwrap();
+        assert_eq!(
+            mac.mac().unwrap().hmacsha256_tag().unwrap().as_ref(),
+            EXAMPLE_BYTES
+        );
+    }
+
+    #[test]
+    fn parse_dasherized() {
+        let mac = CryptoUri::parse_dasherized(EXAMPLE_DASHERIZED).unwrap();
+        assert_eq!(
+            mac.mac().unwrap().hmacsha256_tag().unwrap().as_ref(),
+            EXAMPLE_BYTES
+        );
+    }
+
+    #[test]
+    fn serialize_uri() {
+        let tag: HmacSha256Tag = EXAMPLE_BYTES.try_into().unwrap();
+        assert_eq!(&tag.to_uri_string(), EXAMPLE_URI);
+    }
+
+    #[test]
+    fn serialize_dasherized() {
+        let tag: HmacSha256Tag = EXAMPLE_BYTES.try_into().unwrap();
+        assert_eq!(&tag.to_dasherized_string(), EXAMPLE_DASHERIZED);
+    }
+
+    #[test]
+    fn ct_eq() {
+        use subtle::ConstantTimeEq;
+
+        let tag = HmacSha256Tag::try_from(EXAMPLE_BYTES).unwrap();
+        let same = HmacSha256Tag::try_from(EXAMPLE_BYTES).unwrap();
+        let other = HmacSha256Tag([0u8; 32]);
+
+        assert!(bool::from(tag.ct_eq(&same)));
+        assert!(!bool::from(tag.ct_eq(&other)));
+        assert!(tag == same);
+        assert!(tag != other);
+    }
+
+    #[cfg(feature = "hmac")]
+    #[test]
+    fn compute() {
+        let key = CryptoUri::parse_uri(EXAMPLE_KEY).unwrap();
+        let mac = cryptouri::Mac::compute(key.secret_key().unwrap(), b"Hi There").unwrap();
+        assert_eq!(mac.to_uri_string(), EXAMPLE_URI);
+    }
+
+    #[cfg(feature = "hmac")]
+    #[test]
+    fn verify() {
+        let key = CryptoUri::parse_uri(EXAMPLE_KEY).unwrap();
+        let mac = CryptoUri::parse_uri(EXAMPLE_URI).unwrap();
+        let mac = mac.mac().unwrap();
+        assert!(mac.verify(key.secret_key().unwrap(), b"Hi There").is_ok());
+        assert!(mac.verify(key.secret_key().unwrap(), b"Bye There").is_err());
+    }
+}
@@ -0,0 +1,102 @@
+//! minisign and signify conversion tests
+
+#![cfg(feature = "minisign")]
+
+use cryptouri::{CryptoUri, Encodable, Error, PublicKey, Signature};
+
+/// Public key of the `minisign` project itself
+const MINISIGN_PUBLIC_KEY: &str = "RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3";
+
+const MINISIGN_KEY_ID: &str = "E7620F1842B4E81F";
+
+const PUBLIC_KEY_FILE: &str = "untrusted comment: minisign public key 0807060504030201
+RWQBAgMEBQYHCDtqJ7zOtqQtYqOo0CpvDXNlMhV3HeJDpjrASKGLWdop
+";
+
+const PUBLIC_KEY_HEX: &str = "3b6a27bcceb6a42d62a3a8d02a6f0d73653215771de243a63ac048a18b59da29";
+
+const SIGNATURE_FILE: &str = "untrusted comment: signature from minisign secret key
+RWQBAgMEBQYHCL0jqZfeO51p54NUHNgB0gj3FcEdABb+i0x7WQ2KMMK02b7JbausbD8FEGH/ZlGKT8qJcf90erbFNeQlPTAIigk=
+trusted comment: timestamp:1700000000\tfile:hello.txt
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==
+";
+
+const SIGNATURE_HEX: &str = "bd23a997de3b9d69e783541cd801d208f715c11d0016fe8b4c7b590d8a30c2b4d9bec96dabac6c3f051061ff66518a4fca8971ff747ab6c535e4253d30088a09";
+
+/// `SIGNATURE_FILE` with the prehashed (`ED`) algorithm identifier
+fn prehashed_signature_file() -> String {
+    SIGNATURE_FILE.replace("RWQBAgMEBQYHCL0j", "RUQBAgMEBQYHCL0j")
+}
+
+#[test]
+fn bare_public_key() {
+    let uri = CryptoUri::from_minisign_public_key(MINISIGN_PUBLIC_KEY).unwrap();
+    assert!(uri.public_key().unwrap().is_ed25519_key());
+    assert_eq!(uri.fragment(), Some(MINISIGN_KEY_ID));
+}
+
+#[test]
+fn public_key_file_round_trip() {
+    let key = PublicKey::from_minisign(PUBLIC_KEY_FILE).unwrap();
+    assert_eq!(key.to_hex(), PUBLIC_KEY_HEX);
+
+    let uri = CryptoUri::from_minisign_public_key(PUBLIC_KEY_FILE).unwrap();
+    assert_eq!(uri.fragment(), Some("0807060504030201"));
+    assert_eq!(uri.to_minisign_public_key().unwrap(), PUBLIC_KEY_FILE);
+    assert_eq!(
+        key.to_minisign(0x0807060504030201).unwrap(),
+        PUBLIC_KEY_FILE
+    );
+}
+
+#[test]
+fn signature_file() {
+    let sig = Signature::from_minisign(SIGNATURE_FILE).unwrap();
+    assert_eq!(sig.to_hex(), SIGNATURE_HEX);
+
+    let uri = CryptoUri::from_minisign_signature(SIGNATURE_FILE).unwrap();
+    assert!(uri.signature().unwrap().is_ed25519_signature());
+    assert_eq!(uri.fragment(), Some("0807060504030201"));
+}
+
+#[test]
+fn legacy_signature_is_not_prehashed() {
+    assert_eq!(
+        Signature::from_minisign_prehashed(SIGNATURE_FILE)
+            .err()
+            .unwrap(),
+        Error::Algorithm("Ed".to_owned())
+    );
+}
+
+#[test]
+fn prehashed_signature() {
+    let sig = Signature::from_minisign_prehashed(&prehashed_signature_file()).unwrap();
+    assert_eq!(sig.to_hex(), SIGNATURE_HEX);
+}
+
+#[test]
+fn reject_prehashed_signature_as_legacy() {
+    let file = prehashed_signature_file();
+
+    assert_eq!(
+        Signature::from_minisign(&file).err().unwrap(),
+        Error::Algorithm("ED".to_owned())
+    );
+    assert_eq!(
+        CryptoUri::from_minisign_signature(&file).err().unwrap(),
+        Error::Algorithm("ED".to_owned())
+    );
+}
+
+#[test]
+fn reject_signature_as_public_key() {
+    assert!(PublicKey::from_minisign(SIGNATURE_FILE).is_err());
+}
+
+#[test]
+fn reject_missing_key_id() {
+    let key = PublicKey::from_hex("ed25519", PUBLIC_KEY_HEX).unwrap();
+    let uri = CryptoUri::parse_uri(&key.to_uri_string()).unwrap();
+    assert!(uri.to_minisign_public_key().is_err());
+}
@@ -0,0 +1,54 @@
+//! Memory-locked secret key storage tests
+#![cfg(feature = "mlock")]
+
+use cryptouri::secret_key::{
+    Aes128GcmKey, Aes256GcmKey, ChaCha20Poly1305Key, Ed25519SecretKey, HkdfSha256Key,
+};
+use std::convert::TryFrom;
+
+#[test]
+fn round_trip() {
+    let bytes = [0x42; 32];
+
+    assert_eq!(
+        Aes128GcmKey::try_from(&bytes[..16]).unwrap().as_ref(),
+        &bytes[..16]
+    );
+    assert_eq!(Aes256GcmKey::try_from(&bytes[..]).unwrap().as_ref(), &bytes);
+    assert_eq!(
+        ChaCha20Poly1305Key::try_from(&bytes[..]).unwrap().as_ref(),
+        &bytes
+    );
+    assert_eq!(
+        Ed25519SecretKey::try_from(&bytes[..]).unwrap().as_ref(),
+        &bytes
+    );
+    assert_eq!(
+        HkdfSha256Key::try_from(&bytes[..]).unwrap().as_ref(),
+        &bytes
+    );
+}
+
+#[test]
+fn clone() {
+    let key = Aes256GcmKey::try_from([0x42; 32].as_ref()).unwrap();
+    let cloned = key.clone();
+    assert_eq!(cloned.as_ref(), key.as_ref());
+    assert_eq!(cloned.is_locked(), key.is_locked());
+}
+
+/// Keys are still usable once the limit on locked memory has been reached
+#[test]
+fn fallback() {
+    let keys = (0..4096u32)
+        .map(|i| {
+            let mut bytes = [0u8; 32];
+            bytes[..4].copy_from_slice(&i.to_le_bytes());
+            Aes256GcmKey::try_from(bytes.as_ref()).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for (i, key) in keys.iter().enumerate() {
+        assert_eq!(key.as_ref()[..4], (i as u32).to_le_bytes());
+    }
+}
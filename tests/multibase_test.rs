@@ -0,0 +1,108 @@
+//! Multibase/multicodec and `did:key` conversion tests
+
+#![cfg(feature = "multibase")]
+
+/// Ed25519 public key test
+///
+/// Uses public key from RFC 8032 test vector: "TEST 1" secret key
+/// https://tools.ietf.org/html/rfc8032#section-7.1
+mod ed25519 {
+    use cryptouri::{multibase::Multibase, CryptoUri, Encodable, PublicKey};
+
+    const EXAMPLE_URI: &str =
+        "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv";
+
+    const EXAMPLE_DID_KEY: &str = "did:key:z6MktwupdmLXVVqTzCw4i46r4uGyosGXRnR3XjN4Zq7oMMsw";
+
+    #[test]
+    fn to_did_key() {
+        let uri = CryptoUri::parse_uri(EXAMPLE_URI).unwrap();
+        assert_eq!(
+            uri.public_key().unwrap().to_did_key().unwrap(),
+            EXAMPLE_DID_KEY
+        );
+    }
+
+    #[test]
+    fn from_did_key() {
+        let key = PublicKey::from_did_key(EXAMPLE_DID_KEY).unwrap();
+        assert_eq!(key.to_uri_string(), EXAMPLE_URI);
+    }
+
+    #[test]
+    fn from_did_url() {
+        let did_url = format!(
+            "{}#z6MktwupdmLXVVqTzCw4i46r4uGyosGXRnR3XjN4Zq7oMMsw",
+            EXAMPLE_DID_KEY
+        );
+        let key = PublicKey::from_did_key(&did_url).unwrap();
+        assert_eq!(key.to_uri_string(), EXAMPLE_URI);
+    }
+
+    #[test]
+    fn multibase() {
+        let key = CryptoUri::parse_uri(EXAMPLE_URI).unwrap();
+        let key = key.public_key().unwrap();
+
+        for (base, encoded) in &[
+            (
+                Multibase::Base16,
+                "fed01d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            ),
+            (
+                Multibase::Base58Btc,
+                "z6MktwupdmLXVVqTzCw4i46r4uGyosGXRnR3XjN4Zq7oMMsw",
+            ),
+            (
+                Multibase::Base64Url,
+                "u7QHXWpgBgrEKt9VL_tPJZAc6DuFy89qmIyWvAhpo9wdRGg",
+            ),
+        ] {
+            assert_eq!(&key.to_multibase(*base).unwrap(), encoded);
+            assert_eq!(
+                PublicKey::from_multibase(encoded).unwrap().to_uri_string(),
+                EXAMPLE_URI
+            );
+        }
+    }
+
+    #[test]
+    fn multicodec() {
+        let key = CryptoUri::parse_uri(EXAMPLE_URI).unwrap();
+        let bytes = key.public_key().unwrap().to_multicodec().unwrap();
+        assert_eq!(&bytes[..2], &[0xed, 0x01]);
+        assert_eq!(
+            PublicKey::from_multicodec(&bytes).unwrap().to_uri_string(),
+            EXAMPLE_URI
+        );
+    }
+}
+
+/// X25519 public key test
+///
+/// Uses Alice's public key from RFC 7748 Section 6.1
+mod x25519 {
+    use cryptouri::PublicKey;
+
+    const EXAMPLE_DID_KEY: &str = "did:key:z6LSkdrX4EvewpktHBjvNxRDogPdC5iVF8LT3LPKefGAgi89";
+
+    #[test]
+    fn did_key_round_trip() {
+        let key = PublicKey::from_did_key(EXAMPLE_DID_KEY).unwrap();
+        assert!(key.is_x25519_key());
+        assert_eq!(key.to_did_key().unwrap(), EXAMPLE_DID_KEY);
+    }
+}
+
+#[test]
+fn unknown_multicodec() {
+    // secp256k1-pub (0xe7)
+    let mut bytes = vec![0xe7, 0x01];
+    bytes.extend_from_slice(&[2; 33]);
+    assert!(cryptouri::PublicKey::from_multicodec(&bytes).is_err());
+}
+
+#[test]
+fn not_a_did_key() {
+    assert!(cryptouri::PublicKey::from_did_key("did:web:example.com").is_err());
+}
@@ -0,0 +1,27 @@
+//! Links `cryptouri` with its default features disabled but without a
+//! `#[global_allocator]`, which fails if anything still depends on `alloc`
+
+#![no_std]
+
+use core::panic::PanicInfo;
+use cryptouri::{public_key::Ed25519PublicKey, Encodable};
+
+/// Public key from RFC 8032 test vector: "TEST 1" secret key
+const PUBLIC_KEY_URI: &str =
+    "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv";
+
+/// Parse and re-encode a public key, returning the encoded length (or 0 on
+/// error)
+#[no_mangle]
+pub extern "C" fn cryptouri_round_trip() -> usize {
+    let mut buf = [0u8; 128];
+
+    Ed25519PublicKey::parse_uri(PUBLIC_KEY_URI)
+        .and_then(|key| key.encode_into(&mut buf).map(str::len))
+        .unwrap_or(0)
+}
+
+#[panic_handler]
+fn panic(_info: &PanicInfo<'_>) -> ! {
+    loop {}
+}
@@ -0,0 +1,112 @@
+//! Algorithm plugin tests
+//!
+//! Plugins are registered process-wide, so each test uses its own
+//! algorithm identifier.
+
+#![cfg(feature = "alloc")]
+#![cfg(feature = "std")]
+
+use cryptouri::{
+    algorithm::Length,
+    plugin::{self, Plugin},
+    CryptoUri, Encodable, Error, Kind,
+};
+
+/// Example plugin supporting 4-byte public and secret keys
+struct Example(&'static str);
+
+impl Plugin for Example {
+    fn alg_id(&self) -> &'static str {
+        self.0
+    }
+
+    fn length(&self, kind: Kind) -> Option<Length> {
+        match kind {
+            Kind::PublicKey | Kind::SecretKey => Some(Length::Fixed(4)),
+            _ => None,
+        }
+    }
+
+    fn validate(&self, _kind: Kind, bytes: &[u8]) -> Result<(), Error> {
+        if bytes == [0, 0, 0, 0] {
+            return Err(Error::Parse);
+        }
+
+        Ok(())
+    }
+}
+
+const EXAMPLE_BYTES: &[u8] = &[1, 2, 3, 4];
+
+#[test]
+fn parse_public_key() {
+    plugin::register(&Example("example")).unwrap();
+
+    let uri = "crypto:pub:key:example:qypqxpqmvw3cd";
+    let key = CryptoUri::parse_uri(uri).unwrap();
+    let custom = key.public_key().unwrap().custom().unwrap();
+    assert_eq!(custom.kind(), Kind::PublicKey);
+    assert_eq!(custom.alg_id(), "example");
+    assert_eq!(custom.as_ref(), EXAMPLE_BYTES);
+    assert_eq!(key.to_uri_string(), uri);
+    assert_eq!(
+        key.to_dasherized_string(),
+        "crypto-pub-key-example-qypqxpq7s6c93"
+    );
+}
+
+#[test]
+fn parse_secret_key() {
+    plugin::register(&Example("examplesec")).unwrap();
+
+    let key = cryptouri::SecretKey::new("examplesec", EXAMPLE_BYTES).unwrap();
+    assert!(key.is_custom());
+    assert_eq!(key.algorithm(), None);
+    assert_eq!(key.alg_id(), "examplesec");
+
+    let parsed = CryptoUri::parse_dasherized(&key.to_dasherized_string()).unwrap();
+    assert_eq!(
+        parsed.secret_key().unwrap().custom().unwrap().as_ref(),
+        EXAMPLE_BYTES
+    );
+}
+
+#[test]
+fn reject_unsupported_kind() {
+    plugin::register(&Example("examplesig")).unwrap();
+    assert!(cryptouri::Signature::new("examplesig", &[0; 4]).is_err());
+}
+
+#[test]
+fn reject_invalid_data() {
+    plugin::register(&Example("exampleinvalid")).unwrap();
+    assert!(cryptouri::PublicKey::new("exampleinvalid", &[1, 2, 3]).is_err());
+    assert!(cryptouri::PublicKey::new("exampleinvalid", &[0; 4]).is_err());
+}
+
+#[test]
+fn reject_duplicate_alg_id() {
+    assert!(plugin::register(&Example("ed25519")).is_err());
+
+    plugin::register(&Example("exampledup")).unwrap();
+    assert!(plugin::register(&Example("exampledup")).is_err());
+}
+
+#[test]
+fn reject_malformed_alg_id() {
+    for alg_id in [
+        "", "Example", "ex:ample", "ex_ample", "ex+ample", "ex#ample", "ex ample", "exämple",
+    ] {
+        assert_eq!(
+            plugin::register(Box::leak(Box::new(Example(alg_id))))
+                .err()
+                .unwrap(),
+            Error::Algorithm(alg_id.to_owned())
+        );
+    }
+}
+
+#[test]
+fn reject_unregistered() {
+    assert!(CryptoUri::parse_uri("crypto:pub:key:unregistered:qypqxpqmvw3cd").is_err());
+}
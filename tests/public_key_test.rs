@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 /// Ed25519 public key test
 ///
 /// Uses public key from RFC 8032 test vector: "TEST 1" secret key
@@ -48,3 +50,80 @@ mod ed25519 {
         assert_eq!(&key.to_dasherized_string(), EXAMPLE_DASHERIZED);
     }
 }
+
+/// X25519 public key test
+///
+/// Uses Alice's public key from RFC 7748 Section 6.1
+/// https://tools.ietf.org/html/rfc7748#section-6.1
+mod x25519 {
+    use cryptouri::public_key::X25519PublicKey;
+    use cryptouri::{CryptoUri, Encodable};
+    use std::convert::TryInto;
+
+    const EXAMPLE_URI: &str =
+        "crypto:pub:key:x25519:s5s0qzvfxzn4gayt0hwtg0hhtgxm7wsdycup4a8t5j5ca25mfe4qxwtz05";
+
+    const EXAMPLE_DASHERIZED: &str =
+        "crypto-pub-key-x25519-s5s0qzvfxzn4gayt0hwtg0hhtgxm7wsdycup4a8t5j5ca25mfe4qs3kadt";
+
+    const EXAMPLE_BYTES: &[u8] = &[
+        133, 32, 240, 9, 137, 48, 167, 84, 116, 139, 125, 220, 180, 62, 247, 90, 13, 191, 58, 13,
+        38, 56, 26, 244, 235, 164, 169, 142, 170, 155, 78, 106,
+    ];
+
+    #[test]
+    fn parse_uri() {
+        let key = CryptoUri::parse_uri(EXAMPLE_URI).unwrap();
+        assert_eq!(
+            key.public_key().unwrap().x25519_key().unwrap().as_ref(),
+            EXAMPLE_BYTES
+        );
+    }
+
+    #[test]
+    fn parse_dasherized() {
+        let key = CryptoUri::parse_dasherized(EXAMPLE_DASHERIZED).unwrap();
+        assert_eq!(
+            key.public_key().unwrap().x25519_key().unwrap().as_ref(),
+            EXAMPLE_BYTES
+        );
+    }
+
+    #[test]
+    fn serialize_uri() {
+        let key: X25519PublicKey = EXAMPLE_BYTES.try_into().unwrap();
+        assert_eq!(&key.to_uri_string(), EXAMPLE_URI);
+    }
+
+    #[test]
+    fn serialize_dasherized() {
+        let key: X25519PublicKey = EXAMPLE_BYTES.try_into().unwrap();
+        assert_eq!(&key.to_dasherized_string(), EXAMPLE_DASHERIZED);
+    }
+}
+
+/// Hex and base64 conversions
+mod hex_base64 {
+    use cryptouri::{Encodable, PublicKey};
+
+    const EXAMPLE_URI: &str =
+        "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv";
+
+    const EXAMPLE_HEX: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    const EXAMPLE_BASE64: &str = "11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=";
+
+    #[test]
+    fn hex_round_trip() {
+        let key = PublicKey::from_hex("ed25519", EXAMPLE_HEX).unwrap();
+        assert_eq!(key.to_uri_string(), EXAMPLE_URI);
+        assert_eq!(key.to_hex(), EXAMPLE_HEX);
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let key = PublicKey::from_base64("ed25519", EXAMPLE_BASE64).unwrap();
+        assert_eq!(key.to_uri_string(), EXAMPLE_URI);
+        assert_eq!(key.to_base64(), EXAMPLE_BASE64);
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 macro_rules! secret_key_test {
     ($name:ident, $keytype:ident, $uri:expr, $dasherized:expr, $bytes:expr) => {
         mod $name {
@@ -86,6 +88,30 @@ secret_key_test!(
     ]
 );
 
+// HMAC-SHA-256 secret key test
+secret_key_test!(
+    hmacsha256_key,
+    HmacSha256Key,
+    "crypto:sec:key:hmacsha256:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9s47lm50",
+    "crypto-sec-key-hmacsha256-pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9szayyyz",
+    &[0xb; 32]
+);
+
+/// HMAC keys shorter than the hash output size are rejected
+#[test]
+fn hmacsha256_key_too_short() {
+    use cryptouri::{secret_key::HmacSha256Key, Error};
+    use std::convert::TryFrom;
+
+    assert_eq!(
+        HmacSha256Key::try_from([0xb; 16].as_ref()).err(),
+        Some(Error::Length {
+            actual: 16,
+            expected: 32
+        })
+    );
+}
+
 /// Tests for serializing a combined HKDF-SHA-256+AES-256-GCM key
 mod hkdfsha256_aes256gcm_key {
     use cryptouri::{
@@ -120,3 +146,197 @@ mod hkdfsha256_aes256gcm_key {
         );
     }
 }
+
+// HKDF-SHA-384 secret key test
+secret_key_test!(
+    hkdfsha384_key,
+    HkdfSha384Key,
+    "crypto:sec:key:hkdfsha384:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skp4u457",
+    "crypto-sec-key-hkdfsha384-pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skgu96km",
+    &[0xb; 48]
+);
+
+/// Tests for combining KDFs other than HKDF-SHA-256 with other algorithms
+mod kdf_combinations {
+    use cryptouri::{secret_key::Algorithm, CryptoUri, Encodable, Error};
+
+    const HKDFSHA512_CHACHA20POLY1305_URI: &str = "crypto:sec:key:hkdfsha512+chacha20poly1305:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzc2s496m";
+
+    const KBKDFHMACSHA256_AES128GCM_DASHERIZED: &str = "crypto-sec-key-kbkdfhmacsha256_aes128gcm-pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9snm2ael";
+
+    #[test]
+    fn parse_hkdfsha512_chacha20poly1305() {
+        let uri = CryptoUri::parse_uri(HKDFSHA512_CHACHA20POLY1305_URI).unwrap();
+        let key = uri.secret_key().unwrap().hkdfsha512_key().unwrap();
+        assert_eq!(key.derived_alg(), Some(Algorithm::ChaCha20Poly1305));
+        assert_eq!(key.to_uri_string(), HKDFSHA512_CHACHA20POLY1305_URI);
+    }
+
+    #[test]
+    fn parse_kbkdfhmacsha256_aes128gcm() {
+        let uri = CryptoUri::parse_dasherized(KBKDFHMACSHA256_AES128GCM_DASHERIZED).unwrap();
+        let key = uri.secret_key().unwrap().kbkdfhmacsha256_key().unwrap();
+        assert_eq!(key.derived_alg(), Some(Algorithm::Aes128Gcm));
+        assert_eq!(
+            key.to_dasherized_string(),
+            KBKDFHMACSHA256_AES128GCM_DASHERIZED
+        );
+    }
+
+    /// Only KDFs can appear first in a combination
+    #[test]
+    fn reject_non_kdf() {
+        let result = cryptouri::SecretKey::new_combination(&["aes256gcm", "hkdfsha256"], &[0; 32]);
+        assert_eq!(result.err(), Some(Error::Algorithm("aes256gcm".to_owned())));
+    }
+}
+
+/// Tests for multi-level combinations (i.e. KDF pipelines)
+mod kdf_pipelines {
+    use cryptouri::{secret_key::Algorithm, CryptoUri, Error, SecretKey};
+
+    const HKDFSHA256_HKDFSHA256_AES256GCM_URI: &str = "crypto:sec:key:hkdfsha256+hkdfsha256+aes256gcm:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9s3s30al";
+
+    const ARGON2ID_HKDFSHA256_CHACHA20POLY1305_DASHERIZED: &str = "crypto-sec-key-argon2id_hkdfsha256_chacha20poly1305-vdhhyun9vd6zq6r0wfek2grzv968getj0ys8xarpwpkx2qngdzx";
+
+    #[test]
+    fn parse_hkdfsha256_hkdfsha256_aes256gcm() {
+        let uri = CryptoUri::parse_uri(HKDFSHA256_HKDFSHA256_AES256GCM_URI).unwrap();
+        let key = uri.secret_key().unwrap();
+        let pipeline = key.pipeline().unwrap();
+        assert_eq!(
+            pipeline.stages(),
+            &[Algorithm::HkdfSha256, Algorithm::Aes256Gcm]
+        );
+        assert_eq!(pipeline.output(), Algorithm::Aes256Gcm);
+        assert_eq!(
+            key.to_uri_string().as_str(),
+            HKDFSHA256_HKDFSHA256_AES256GCM_URI
+        );
+    }
+
+    #[test]
+    fn parse_argon2id_hkdfsha256_chacha20poly1305() {
+        let uri =
+            CryptoUri::parse_dasherized(ARGON2ID_HKDFSHA256_CHACHA20POLY1305_DASHERIZED).unwrap();
+        let key = uri.secret_key().unwrap();
+        assert_eq!(
+            key.argon2id_key().unwrap().as_ref(),
+            b"correct horse battery staple"
+        );
+        assert_eq!(
+            key.pipeline().unwrap().stages(),
+            &[Algorithm::HkdfSha256, Algorithm::ChaCha20Poly1305]
+        );
+        assert_eq!(
+            key.to_dasherized_string().as_str(),
+            ARGON2ID_HKDFSHA256_CHACHA20POLY1305_DASHERIZED
+        );
+    }
+
+    /// Only KDFs can feed into subsequent stages
+    #[test]
+    fn reject_non_kdf_stage() {
+        let result = SecretKey::new_combination(
+            &["hkdfsha256", "aes256gcm", "chacha20poly1305"],
+            &[0xb; 32],
+        );
+        assert_eq!(result.err(), Some(Error::Algorithm("aes256gcm".to_owned())));
+    }
+}
+
+/// Hex and base64 conversions
+mod hex_base64 {
+    use cryptouri::SecretKey;
+
+    /// RFC 8032 "TEST 1" secret key
+    const EXAMPLE_HEX: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+
+    #[test]
+    fn hex_round_trip() {
+        let key = SecretKey::from_hex("ed25519", EXAMPLE_HEX).unwrap();
+        assert!(key.is_ed25519_key());
+        assert_eq!(key.to_hex().as_str(), EXAMPLE_HEX);
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let key = SecretKey::from_hex("aes128gcm", "000102030405060708090a0b0c0d0e0f").unwrap();
+        let encoded = key.to_base64();
+        assert_eq!(encoded.as_str(), "AAECAwQFBgcICQoLDA0ODw==");
+
+        let decoded = SecretKey::from_base64("aes128gcm", &encoded).unwrap();
+        assert_eq!(decoded.as_bytes(), key.as_bytes());
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert!(SecretKey::from_hex("aes256gcm", "000102030405060708090a0b0c0d0e0f").is_err());
+    }
+}
+
+/// Constant-time equality
+mod constant_time_eq {
+    use cryptouri::{
+        secret_key::{Aes256GcmKey, HkdfSha256Key},
+        SecretKey,
+    };
+    use std::convert::TryFrom;
+
+    #[test]
+    fn concrete_key() {
+        let key = Aes256GcmKey::try_from([1u8; 32].as_ref()).unwrap();
+        assert!(key == Aes256GcmKey::try_from([1u8; 32].as_ref()).unwrap());
+        assert!(key != Aes256GcmKey::try_from([2u8; 32].as_ref()).unwrap());
+    }
+
+    #[test]
+    fn kdf_key_pipeline() {
+        let key = SecretKey::new_combination(&["hkdfsha256", "aes256gcm"], &[1u8; 32]).unwrap();
+        let same = SecretKey::new_combination(&["hkdfsha256", "aes256gcm"], &[1u8; 32]).unwrap();
+        let other = SecretKey::new_combination(&["hkdfsha256", "aes128gcm"], &[1u8; 32]).unwrap();
+
+        assert!(key == same);
+        assert!(key != other);
+        assert!(key != SecretKey::new("hkdfsha256", &[1u8; 32]).unwrap());
+        assert!(
+            HkdfSha256Key::try_from([1u8; 32].as_ref()).unwrap()
+                == HkdfSha256Key::try_from([1u8; 32].as_ref()).unwrap()
+        );
+    }
+
+    #[test]
+    fn different_algorithms() {
+        let aes = SecretKey::new("aes256gcm", &[1u8; 32]).unwrap();
+        let chacha = SecretKey::new("chacha20poly1305", &[1u8; 32]).unwrap();
+
+        assert!(!bool::from(aes.ct_eq(&chacha)));
+        assert!(aes != chacha);
+    }
+}
+
+/// Zeroizing serialization
+mod zeroizing {
+    use cryptouri::{CryptoUri, Encodable, SecretKey};
+    use zeroize::Zeroizing;
+
+    const EXAMPLE_URI: &str =
+        "crypto:sec:key:hkdfsha256+aes256gcm:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9sxm0sk0";
+
+    #[test]
+    fn to_uri_string() {
+        let crypto_uri = CryptoUri::parse_uri(EXAMPLE_URI).unwrap();
+        let key: &SecretKey = crypto_uri.secret_key().unwrap();
+        let uri: Zeroizing<String> = key.to_uri_string();
+        assert_eq!(uri.as_str(), EXAMPLE_URI);
+
+        // The `Encodable` impl still returns a plain `String`
+        assert_eq!(Encodable::to_uri_string(key), EXAMPLE_URI);
+    }
+
+    #[test]
+    fn with_fragment() {
+        let uri = format!("{}#backup", EXAMPLE_URI);
+        assert_eq!(CryptoUri::parse_uri(&uri).unwrap().to_uri_string(), uri);
+    }
+}
@@ -1 +1,40 @@
-// TODO: actually write signature test!
+#![cfg(feature = "alloc")]
+
+/// Ed25519 signature test
+///
+/// Uses signature from RFC 8032 test vector: "TEST 1"
+/// https://tools.ietf.org/html/rfc8032#section-7.1
+mod ed25519 {
+    use cryptouri::Signature;
+
+    const EXAMPLE_HEX: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+    #[test]
+    fn hex_round_trip() {
+        let sig = Signature::from_hex("ed25519", EXAMPLE_HEX).unwrap();
+        assert!(sig.is_ed25519_signature());
+        assert_eq!(sig.to_hex(), EXAMPLE_HEX);
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let sig = Signature::from_hex("ed25519", EXAMPLE_HEX).unwrap();
+        let decoded = Signature::from_base64("ed25519", &sig.to_base64()).unwrap();
+        assert_eq!(decoded.as_ref(), sig.as_ref());
+    }
+
+    #[test]
+    fn constant_time_eq() {
+        let sig = Signature::from_hex("ed25519", EXAMPLE_HEX).unwrap();
+        assert!(sig == Signature::from_hex("ed25519", EXAMPLE_HEX).unwrap());
+
+        let mut bytes = sig.as_ref().to_vec();
+        bytes[0] ^= 1;
+        assert!(sig != Signature::new("ed25519", &bytes).unwrap());
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert!(Signature::from_hex("ed25519", &EXAMPLE_HEX[..64]).is_err());
+    }
+}
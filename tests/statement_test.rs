@@ -0,0 +1,154 @@
+//! Signed statement tests
+
+#![cfg(all(feature = "alloc", feature = "ed25519-dalek", feature = "sha2"))]
+
+use cryptouri::{
+    statement::Signer, CryptoUri, CryptoUriRef, Encodable, Error, Hash, KeyPair, Kind, PublicKey,
+    SecretKey, Signature, Statement,
+};
+
+/// RFC 8032 test vector: "TEST 1" secret key
+const SECRET_KEY: [u8; 32] = [
+    0x9d, 0x61, 0xb1, 0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec, 0x2c, 0xc4,
+    0x44, 0x49, 0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03, 0x1c, 0xae, 0x7f, 0x60,
+];
+
+const ARTIFACT: &[u8] = b"release-1.0.0.tar.gz";
+
+fn key_pair() -> KeyPair {
+    KeyPair::new(SecretKey::new("ed25519", &SECRET_KEY).unwrap()).unwrap()
+}
+
+fn statement() -> Statement {
+    let hash = Hash::compute("sha256", ARTIFACT).unwrap();
+    Statement::sign(&key_pair(), hash).unwrap()
+}
+
+#[test]
+fn sign_and_verify() {
+    let statement = statement();
+    statement.verify().unwrap();
+    statement.verify_data(ARTIFACT).unwrap();
+    statement.verify_with(key_pair().public_key()).unwrap();
+}
+
+#[test]
+fn verify_data_mismatch() {
+    assert_eq!(
+        statement().verify_data(b"something else").err().unwrap(),
+        Error::Verification
+    );
+}
+
+#[test]
+fn parse_uri_round_trip() {
+    let uri = statement().to_uri_string();
+    assert!(uri.starts_with("crypto:stmt:ed25519+sha256+ed25519:"));
+
+    let parsed = Statement::parse_uri(&uri).unwrap();
+    parsed.verify_data(ARTIFACT).unwrap();
+    assert_eq!(parsed.to_uri_string(), uri);
+}
+
+#[test]
+fn parse_dasherized_round_trip() {
+    let token = statement().to_dasherized_string();
+    assert!(token.starts_with("crypto-stmt-ed25519_sha256_ed25519-"));
+
+    let parsed = Statement::parse_dasherized(&token).unwrap();
+    parsed.verify_data(ARTIFACT).unwrap();
+    assert_eq!(parsed.to_dasherized_string(), token);
+}
+
+#[test]
+fn fingerprint_signer() {
+    let signed = statement();
+    let key_pair = key_pair();
+    let statement = Statement::new(
+        Hash::new("sha256", signed.hash().as_ref()).unwrap(),
+        Signature::new("ed25519", signed.signature().as_ref()).unwrap(),
+        Signer::Fingerprint(key_pair.fingerprint()),
+    );
+
+    let uri = statement.to_uri_string();
+    assert!(uri.starts_with("crypto:stmt:ed25519+sha256+sha256:"));
+
+    let parsed = Statement::parse_uri(&uri).unwrap();
+    assert!(matches!(parsed.signer(), Signer::Fingerprint(_)));
+    assert_eq!(parsed.verify().err().unwrap(), Error::Verification);
+    parsed.verify_with(key_pair.public_key()).unwrap();
+}
+
+#[test]
+fn wrong_signer() {
+    let other = KeyPair::new(SecretKey::new("ed25519", &[0x42; 32]).unwrap()).unwrap();
+    assert_eq!(
+        statement().verify_with(other.public_key()).err().unwrap(),
+        Error::Verification
+    );
+}
+
+#[test]
+fn tampered_hash() {
+    let signed = statement();
+    let statement = Statement::new(
+        Hash::compute("sha256", b"something else").unwrap(),
+        Signature::new("ed25519", signed.signature().as_ref()).unwrap(),
+        Signer::PublicKey(PublicKey::new("ed25519", key_pair().public_key().as_ref()).unwrap()),
+    );
+
+    assert_eq!(statement.verify().err().unwrap(), Error::Verification);
+}
+
+#[test]
+fn parse_plain_signature() {
+    let signature = statement().signature().to_uri_string();
+    assert_eq!(
+        Statement::parse_uri(&signature).err().unwrap(),
+        Error::Kind {
+            expected: Kind::Statement,
+            actual: Kind::Signature,
+        }
+    );
+}
+
+#[test]
+fn relabelled_hash_algorithm() {
+    let signed = statement();
+    let statement = Statement::new(
+        Hash::new("sha512t256", signed.hash().as_ref()).unwrap(),
+        Signature::new("ed25519", signed.signature().as_ref()).unwrap(),
+        Signer::PublicKey(PublicKey::new("ed25519", key_pair().public_key().as_ref()).unwrap()),
+    );
+
+    assert_eq!(statement.verify().err().unwrap(), Error::Verification);
+}
+
+#[test]
+fn generic_parsers() {
+    let uri = statement().to_uri_string();
+
+    let crypto_uri = CryptoUri::parse_uri(&uri).unwrap();
+    assert!(crypto_uri.is_statement());
+    crypto_uri
+        .statement()
+        .unwrap()
+        .verify_data(ARTIFACT)
+        .unwrap();
+    assert_eq!(crypto_uri.to_uri_string(), uri);
+
+    let uri_ref = CryptoUriRef::parse_uri(&uri).unwrap();
+    assert_eq!(uri_ref.kind(), Kind::Statement);
+    assert_eq!(
+        uri_ref.algorithms().collect::<Vec<_>>(),
+        ["ed25519", "sha256", "ed25519"]
+    );
+}
+
+#[test]
+fn binary_round_trip() {
+    let uri = statement().to_uri_string();
+    let bytes = CryptoUri::parse_uri(&uri).unwrap().to_bytes();
+    let decoded = CryptoUri::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.to_uri_string(), uri);
+}
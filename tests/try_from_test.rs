@@ -0,0 +1,141 @@
+//! Typed extraction tests
+
+#![cfg(feature = "alloc")]
+
+use cryptouri::{
+    hash::Sha256Hash,
+    public_key::Ed25519PublicKey,
+    secret_key::{Aes256GcmKey, HkdfSha256Key},
+    CryptoUri, Error, Kind,
+};
+use std::convert::TryFrom;
+
+const PUBLIC_KEY_URI: &str =
+    "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv";
+
+const PUBLIC_KEY_BYTES: [u8; 32] = [
+    215, 90, 152, 1, 130, 177, 10, 183, 213, 75, 254, 211, 201, 100, 7, 58, 14, 225, 114, 243, 218,
+    166, 35, 37, 175, 2, 26, 104, 247, 7, 81, 26,
+];
+
+const HASH_URI: &str =
+    "crypto:hash:sha256:hfupd0u0q8875s2pgr09mt3zywcqxcdrjcth4895zrlkrusqzkkschwfca";
+
+const PIPELINE_URI: &str =
+    "crypto:sec:key:hkdfsha256+aes256gcm:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9sxm0sk0";
+
+#[test]
+fn parse_as() {
+    let key = CryptoUri::parse_as::<Ed25519PublicKey>(PUBLIC_KEY_URI).unwrap();
+    assert_eq!(key.0, PUBLIC_KEY_BYTES);
+
+    let hash = CryptoUri::parse_as::<Sha256Hash>(HASH_URI).unwrap();
+    assert_eq!(hash.0.len(), 32);
+}
+
+#[test]
+fn try_from() {
+    let uri = CryptoUri::parse_uri(PIPELINE_URI).unwrap();
+    let key = HkdfSha256Key::try_from(uri).unwrap();
+    assert_eq!(key.pipeline().unwrap().stages().len(), 1);
+}
+
+#[test]
+fn wrong_kind() {
+    let err = CryptoUri::parse_as::<Sha256Hash>(PUBLIC_KEY_URI)
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        Error::Type {
+            actual_kind: Kind::PublicKey,
+            actual_alg: "ed25519".to_owned(),
+            expected_kind: Kind::Hash,
+            expected_alg: "sha256",
+        }
+    );
+
+    assert_eq!(
+        err.to_string(),
+        "type invalid: ed25519 public key (expected sha256 hash)"
+    );
+}
+
+#[test]
+fn wrong_algorithm() {
+    assert_eq!(
+        CryptoUri::parse_as::<Aes256GcmKey>(PIPELINE_URI).err(),
+        Some(Error::Type {
+            actual_kind: Kind::SecretKey,
+            actual_alg: "hkdfsha256".to_owned(),
+            expected_kind: Kind::SecretKey,
+            expected_alg: "aes256gcm",
+        })
+    );
+}
+
+#[test]
+fn parse_error() {
+    assert_eq!(
+        CryptoUri::parse_as::<Ed25519PublicKey>("crypto:pub:key:ed25519:bogus").err(),
+        Some(Error::Parse)
+    );
+}
+
+/// Parsing objects of an expected kind
+mod from_uri_str {
+    use super::{HASH_URI, PIPELINE_URI, PUBLIC_KEY_URI};
+    use cryptouri::{Error, Hash, Kind, PublicKey, SecretKey};
+    use subtle_encoding::bech32::{self, Bech32};
+
+    #[test]
+    fn expected_kind() {
+        assert!(PublicKey::from_uri_str(PUBLIC_KEY_URI)
+            .unwrap()
+            .is_ed25519_key());
+        assert!(Hash::from_uri_str(HASH_URI).unwrap().is_sha256_digest());
+        assert!(SecretKey::from_uri_str(PIPELINE_URI)
+            .unwrap()
+            .is_hkdfsha256_key());
+    }
+
+    #[test]
+    fn secret_key_as_public_key() {
+        let err = PublicKey::from_uri_str(PIPELINE_URI).err().unwrap();
+
+        assert_eq!(
+            err,
+            Error::Kind {
+                expected: Kind::PublicKey,
+                actual: Kind::SecretKey,
+            }
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "kind invalid: secret key (expected public key)"
+        );
+    }
+
+    #[test]
+    fn public_key_as_secret_key() {
+        assert_eq!(
+            SecretKey::from_uri_str(PUBLIC_KEY_URI).err(),
+            Some(Error::Kind {
+                expected: Kind::SecretKey,
+                actual: Kind::PublicKey,
+            })
+        );
+    }
+
+    #[test]
+    fn secret_key_errors_omit_input() {
+        let uri = Bech32::new(bech32::DEFAULT_CHARSET, ':')
+            .encode("crypto:sec:key:aes256gcmtypo", [0x42; 32]);
+
+        let err = SecretKey::from_uri_str(&uri).err().unwrap();
+        assert_eq!(err, Error::Parse);
+        assert!(!err.to_string().contains("typo"));
+    }
+}
@@ -0,0 +1,104 @@
+//! Lenient parsing tests for CryptoURIs using unknown algorithms
+
+#![cfg(feature = "alloc")]
+
+use cryptouri::{CryptoUri, Encodable, Error, Kind};
+
+const EXAMPLE_BYTES: &[u8] = &[
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32,
+];
+
+/// Public key using an algorithm which isn't supported (yet)
+mod public_key {
+    use super::*;
+
+    const EXAMPLE_URI: &str =
+        "crypto:pub:key:mldsa44:qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z5tpwxqergd3c8g7rusqy2uy3y";
+
+    const EXAMPLE_DASHERIZED: &str =
+        "crypto-pub-key-mldsa44-qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z5tpwxqergd3c8g7rusqjegm8q";
+
+    #[test]
+    fn parse_uri_lenient() {
+        let uri = CryptoUri::parse_uri_lenient(EXAMPLE_URI).unwrap();
+        let unknown = uri.unknown().unwrap();
+        assert_eq!(unknown.kind(), Kind::PublicKey);
+        assert_eq!(unknown.alg_id(), "mldsa44");
+        assert_eq!(unknown.as_ref(), EXAMPLE_BYTES);
+        assert_eq!(uri.to_uri_string(), EXAMPLE_URI);
+        assert_eq!(uri.to_dasherized_string(), EXAMPLE_DASHERIZED);
+    }
+
+    #[test]
+    fn parse_dasherized_lenient() {
+        let uri = CryptoUri::parse_dasherized_lenient(EXAMPLE_DASHERIZED).unwrap();
+        assert!(uri.is_unknown());
+        assert_eq!(uri.to_dasherized_string(), EXAMPLE_DASHERIZED);
+        assert_eq!(uri.to_uri_string(), EXAMPLE_URI);
+    }
+
+    #[test]
+    fn parse_uri_strict() {
+        match CryptoUri::parse_uri(EXAMPLE_URI) {
+            Err(Error::Algorithm(alg)) => assert_eq!(alg, "mldsa44"),
+            _ => panic!("expected algorithm error"),
+        }
+    }
+}
+
+/// Known KDF combined with an unknown algorithm
+mod combination {
+    use super::*;
+
+    const EXAMPLE_URI: &str = "crypto:sec:key:hkdfsha256+xchacha20poly1305:qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z5tpwxqergd3c8g7rusqr309u4";
+
+    const EXAMPLE_DASHERIZED: &str = "crypto-sec-key-hkdfsha256_xchacha20poly1305-qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z5tpwxqergd3c8g7rusqq99rk4";
+
+    #[test]
+    fn parse_uri_lenient() {
+        let uri = CryptoUri::parse_uri_lenient(EXAMPLE_URI).unwrap();
+        let unknown = uri.unknown().unwrap();
+        assert_eq!(unknown.kind(), Kind::SecretKey);
+        assert_eq!(unknown.alg_id(), "hkdfsha256+xchacha20poly1305");
+        assert_eq!(uri.to_uri_string(), EXAMPLE_URI);
+        assert_eq!(uri.to_dasherized_string(), EXAMPLE_DASHERIZED);
+    }
+
+    #[test]
+    fn parse_dasherized_lenient() {
+        let uri = CryptoUri::parse_dasherized_lenient(EXAMPLE_DASHERIZED).unwrap();
+        assert_eq!(
+            uri.unknown().unwrap().alg_id(),
+            "hkdfsha256+xchacha20poly1305"
+        );
+        assert_eq!(uri.to_dasherized_string(), EXAMPLE_DASHERIZED);
+    }
+}
+
+#[test]
+fn known_algorithms_parse_normally() {
+    let uri = CryptoUri::parse_uri_lenient(
+        "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv",
+    )
+    .unwrap();
+
+    assert!(uri.public_key().unwrap().is_ed25519_key());
+}
+
+#[test]
+fn known_algorithms_still_validated() {
+    // Ed25519 public key with the wrong length is an error even when lenient
+    match CryptoUri::parse_uri_lenient("crypto:pub:key:ed25519:qypqxpq9qcrsszg2pvxq6rs0zqpt8lfs") {
+        Err(Error::Length { .. }) => (),
+        _ => panic!("expected length error"),
+    }
+}
+
+#[test]
+fn invalid_scheme_rejected() {
+    assert!(CryptoUri::parse_uri_lenient(
+        "crypto:bogus:mldsa44:qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z5tpwxqergd3c8g7rusqy2uy3y"
+    )
+    .is_err());
+}
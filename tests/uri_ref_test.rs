@@ -0,0 +1,101 @@
+//! Zero-copy CryptoURI view tests
+
+#![cfg(feature = "alloc")]
+
+use cryptouri::{CryptoUri, CryptoUriRef, Encodable, Error, Kind};
+
+const PUBLIC_KEY_URI: &str =
+    "crypto:pub:key:ed25519:6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqf03cvv";
+
+const PUBLIC_KEY_DASHERIZED: &str =
+    "crypto-pub-key-ed25519-6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydqlu986g";
+
+const PUBLIC_KEY_BYTES: [u8; 32] = [
+    215, 90, 152, 1, 130, 177, 10, 183, 213, 75, 254, 211, 201, 100, 7, 58, 14, 225, 114, 243, 218,
+    166, 35, 37, 175, 2, 26, 104, 247, 7, 81, 26,
+];
+
+const HASH_URI: &str =
+    "crypto:hash:sha256:hfupd0u0q8875s2pgr09mt3zywcqxcdrjcth4895zrlkrusqzkkschwfca";
+
+const PIPELINE_URI: &str =
+    "crypto:sec:key:hkdfsha256+aes256gcm:pv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9skzctpv9sxm0sk0";
+
+#[test]
+fn parse_uri() {
+    let uri = format!("{}#release-key", HASH_URI);
+    let uri_ref = CryptoUriRef::parse_uri(&uri).unwrap();
+
+    assert_eq!(uri_ref.kind(), Kind::Hash);
+    assert_eq!(uri_ref.alg_id(), "sha256");
+    assert_eq!(uri_ref.fragment(), Some("release-key"));
+    assert_eq!(uri_ref.as_str(), uri);
+    assert_eq!(uri_ref.data_len(), 32);
+}
+
+#[test]
+fn parse_dasherized() {
+    let uri_ref = CryptoUriRef::parse_dasherized(PUBLIC_KEY_DASHERIZED).unwrap();
+    assert_eq!(uri_ref.kind(), Kind::PublicKey);
+    assert_eq!(uri_ref.alg_id(), "ed25519");
+    assert_eq!(uri_ref.fragment(), None);
+}
+
+#[test]
+fn algorithms() {
+    let uri_ref = CryptoUriRef::parse_uri(PIPELINE_URI).unwrap();
+    assert_eq!(uri_ref.kind(), Kind::SecretKey);
+    assert_eq!(uri_ref.alg_id(), "hkdfsha256+aes256gcm");
+    assert_eq!(
+        uri_ref.algorithms().collect::<Vec<_>>(),
+        ["hkdfsha256", "aes256gcm"]
+    );
+}
+
+#[test]
+fn decode_into() {
+    let uri_ref = CryptoUriRef::parse_uri(PUBLIC_KEY_URI).unwrap();
+    let mut out = [0u8; 32];
+    uri_ref.decode_into(&mut out).unwrap();
+    assert_eq!(out, PUBLIC_KEY_BYTES);
+
+    assert_eq!(
+        uri_ref.decode_into(&mut [0u8; 16]),
+        Err(Error::Length {
+            actual: 32,
+            expected: 16
+        })
+    );
+}
+
+#[test]
+fn to_crypto_uri() {
+    let uri = format!("{}#release-key", PUBLIC_KEY_URI);
+    let crypto_uri = CryptoUriRef::parse_uri(&uri)
+        .unwrap()
+        .to_crypto_uri()
+        .unwrap();
+
+    assert_eq!(crypto_uri.public_key().unwrap().as_ref(), PUBLIC_KEY_BYTES);
+    assert_eq!(crypto_uri.fragment(), Some("release-key"));
+    assert_eq!(
+        CryptoUri::parse_uri(PUBLIC_KEY_URI)
+            .unwrap()
+            .to_uri_string(),
+        crypto_uri.public_key().unwrap().to_uri_string()
+    );
+}
+
+#[test]
+fn bad_checksum() {
+    let uri = PUBLIC_KEY_URI.replace("f03cvv", "f03cvw");
+    assert_eq!(CryptoUriRef::parse_uri(&uri).unwrap_err(), Error::Checksum);
+}
+
+#[test]
+fn wrong_encoding() {
+    assert!(matches!(
+        CryptoUriRef::parse_dasherized(PUBLIC_KEY_URI),
+        Err(Error::Parse)
+    ));
+}